@@ -0,0 +1,73 @@
+//! A C-compatible representation of Sulids.
+
+use crate::Sulid;
+
+/// A Sulid split into two `u64` halves with a stable C layout.
+///
+/// `hi` holds the most significant 64 bits (the 48-bit timestamp and the top 16 bits
+/// of the random part) and `lo` the least significant 64 bits, i.e. the halves are in
+/// big-endian order relative to each other. Each half is a native-endian integer, so
+/// C callers can use it without depending on how Rust lays out a `u128`.
+///
+/// # Example
+/// ```rust
+/// use sulid::{CSulid, Sulid};
+///
+/// let sulid = Sulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+/// let c_sulid = CSulid::from(sulid);
+/// assert_eq!(c_sulid.hi, 0x0123_4567_89AB_CDEF);
+/// assert_eq!(c_sulid.lo, 0xFEDC_BA98_7654_3210);
+/// assert_eq!(Sulid::from(c_sulid), sulid);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CSulid {
+    /// The most significant 64 bits
+    pub hi: u64,
+    /// The least significant 64 bits
+    pub lo: u64,
+}
+
+impl From<Sulid> for CSulid {
+    fn from(sulid: Sulid) -> Self {
+        let value = sulid.u128();
+        CSulid {
+            hi: (value >> 64) as u64,
+            lo: value as u64,
+        }
+    }
+}
+
+impl From<CSulid> for Sulid {
+    fn from(c_sulid: CSulid) -> Self {
+        Sulid::from_u128((u128::from(c_sulid.hi) << 64) | u128::from(c_sulid.lo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, 0x2A_DEAD_BEEF_CAFE_BABE, 17, 29);
+        let c_sulid = CSulid::from(sulid);
+        assert_eq!(
+            (u128::from(c_sulid.hi) << 64) | u128::from(c_sulid.lo),
+            sulid.u128()
+        );
+        assert_eq!(Sulid::from(c_sulid), sulid);
+
+        for sulid in [Sulid::MIN, Sulid::MAX] {
+            assert_eq!(Sulid::from(CSulid::from(sulid)), sulid);
+        }
+        assert_eq!(
+            CSulid::from(Sulid::MAX),
+            CSulid {
+                hi: u64::MAX,
+                lo: u64::MAX
+            }
+        );
+        assert_eq!(core::mem::size_of::<CSulid>(), 16);
+    }
+}
@@ -3,11 +3,13 @@
 
 #[cfg(not(feature = "std"))]
 pub use self::no_std_feature::*;
+#[cfg(all(test, feature = "std"))]
+pub(crate) use self::std_feature::tests::controllable_clock;
 #[cfg(feature = "std")]
 pub use self::std_feature::*;
 
 mod no_std_feature {
-    use crate::Sulid;
+    use crate::{Sulid, SulidVersion};
 
     pub(super) enum Version {
         V1 {
@@ -22,6 +24,36 @@ mod no_std_feature {
         },
     }
 
+    /// A read-only view of a generator's worker configuration.
+    ///
+    /// Comparing identities lets deployment checks ensure that generators on different
+    /// nodes don't share a worker identity.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum WorkerIdentity {
+        /// A V1 generator with a data center ID and a machine ID.
+        V1 {
+            /// The ID of the data center (5 bits).
+            data_center_id: u8,
+            /// The ID of the machine within the data center (5 bits).
+            machine_id: u8,
+        },
+        /// A V2 generator with a combined worker ID.
+        V2 {
+            /// The ID of the combination of data_center_id and machine_id.
+            worker_id: u16,
+        },
+    }
+
+    impl WorkerIdentity {
+        /// Returns the Sulid version of the generator.
+        pub const fn version(&self) -> SulidVersion {
+            match self {
+                WorkerIdentity::V1 { .. } => SulidVersion::V1,
+                WorkerIdentity::V2 { .. } => SulidVersion::V2,
+            }
+        }
+    }
+
     /// A struct for generating Snowflake-inspired ULIDs (SULIDs).
     /// This generator combines the benefits of ULID and Snowflake to
     /// ensure unique, lexicographically sortable identifiers across multiple
@@ -77,10 +109,59 @@ mod no_std_feature {
         /// ```
         pub fn v2_new(worker_id: u16) -> Self {
             // Ensure the worker_id is within the 10-bit range.
-            assert!(worker_id < 32, "worker_id must be in the range 0-1023");
+            assert!(worker_id < 1024, "worker_id must be in the range 0-1023");
             SulidGenerator(Version::V2 { worker_id })
         }
 
+        /// Returns the worker configuration of this generator.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::{SulidGenerator, WorkerIdentity};
+        /// let generator = SulidGenerator::v1_new(1, 2);
+        /// assert_eq!(
+        ///     generator.worker_identity(),
+        ///     WorkerIdentity::V1 { data_center_id: 1, machine_id: 2 }
+        /// );
+        /// ```
+        pub fn worker_identity(&self) -> WorkerIdentity {
+            match self.0 {
+                Version::V1 {
+                    data_center_id,
+                    machine_id,
+                } => WorkerIdentity::V1 {
+                    data_center_id,
+                    machine_id,
+                },
+                Version::V2 { worker_id } => WorkerIdentity::V2 { worker_id },
+            }
+        }
+
+        /// Checks whether the worker bits of the given SULID match this generator's
+        /// data center and machine IDs (V1) or worker ID (V2).
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::{Sulid, SulidGenerator};
+        /// let generator = SulidGenerator::v1_new(1, 2);
+        /// assert!(generator.owns(&Sulid::v1_from_parts(1, 1, 1, 2)));
+        /// assert!(!generator.owns(&Sulid::v1_from_parts(1, 1, 2, 1)));
+        /// ```
+        pub fn owns(&self, sulid: &Sulid) -> bool {
+            match self.0 {
+                Version::V1 {
+                    data_center_id,
+                    machine_id,
+                } => {
+                    sulid.v1_data_center_id() == data_center_id
+                        && sulid.v1_machine_id() == machine_id
+                }
+                Version::V2 { worker_id } => sulid.v2_worker_id() == worker_id,
+            }
+        }
+
         /// Generates a new SULID.
         ///
         /// This method generates a 128-bit unique identifier that combines
@@ -158,21 +239,393 @@ mod no_std_feature {
 
 #[cfg(feature = "std")]
 mod std_feature {
+    pub use super::no_std_feature::WorkerIdentity;
     use super::no_std_feature::{SulidGenerator as InnerSulidGenerator, Version};
-    use crate::Sulid;
+    use crate::sulid::{bitmask, std_feature::now};
+    use crate::{Sulid, TimeUnit, ULID_LEN};
     use rand::rngs::StdRng;
-    use rand::SeedableRng;
-    use std::sync::Mutex;
-    use std::time::SystemTime;
+    use rand::{Rng, SeedableRng};
+    use std::fmt;
+    use std::io::{self, Write};
+    use std::sync::atomic::{AtomicU16, Ordering};
+    use std::sync::{Arc, Mutex, TryLockError};
+    use std::time::{Duration, SystemTime};
 
     /// A struct for generating Snowflake-inspired ULIDs (SULIDs).
     /// This generator combines the benefits of ULID and Snowflake to
     /// ensure unique, lexicographically sortable identifiers across multiple
     /// data centers and machines.
+    ///
+    /// The generator is `Send + Sync`: all generation methods take `&self` and
+    /// serialize access to the internal state, so a single generator can be shared
+    /// between threads behind an [`Arc`], e.g. via [`SulidGenerator::into_shared`].
     pub struct SulidGenerator {
         inner: InnerSulidGenerator,
-        /// The random number generator wrapped in a mutex for thread safety.
-        rng: Mutex<StdRng>,
+        /// How the random component is filled.
+        options: Options,
+        /// The mutable generation state wrapped in a mutex for thread safety.
+        state: Mutex<State>,
+        /// The clock used to read the current time.
+        clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+        /// The worker IDs that `generate` cycles through, if any.
+        rotation: Vec<u16>,
+        /// Whether `generate` offsets the worker ID by the calling thread's index.
+        thread_sharded: bool,
+    }
+
+    /// Fails to compile if `T` is not `Send + Sync`.
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    const _: () = assert_send_sync::<SulidGenerator>();
+
+    /// Options controlling how the random component is filled.
+    #[derive(Clone, Copy)]
+    struct Options {
+        /// The number of top bits of the random component that carry a per-millisecond sequence number.
+        seq_bits: u8,
+        /// The number of low bits of the random component reserved for the application.
+        reserved_bits: u8,
+        /// Whether the reserved bits are filled with randomness instead of zeros.
+        fill_reserved: bool,
+        /// The unit of the timestamps read from the clock.
+        time_unit: TimeUnit,
+    }
+
+    impl Default for Options {
+        fn default() -> Self {
+            Options {
+                seq_bits: 0,
+                reserved_bits: 0,
+                fill_reserved: true,
+                time_unit: TimeUnit::Millis,
+            }
+        }
+    }
+
+    struct State {
+        /// The random number generator.
+        rng: StdRng,
+        /// The last SULID produced by monotonic generation.
+        last: Sulid,
+        /// The millisecond of the last sequence number handed out.
+        seq_ms: u64,
+        /// The index of the next worker ID in the rotation.
+        rotation_index: usize,
+        /// The next value of a benchmark counter generator, if any.
+        counter: Option<u128>,
+        /// The last sequence number handed out within `seq_ms`.
+        seq: u128,
+        /// The millisecond and raw value of the last random draw, checked in debug builds.
+        last_draw: Option<(u64, u128)>,
+        /// The millisecond of the most recently stamped SULID.
+        count_ms: u64,
+        /// The number of SULIDs stamped with `count_ms`.
+        count: u64,
+        /// The filter of SULIDs generated so far, if deduplication is enabled.
+        dedup: Option<DedupFilter>,
+    }
+
+    impl State {
+        /// Draws the random component for a SULID stamped with `timestamp_ms`.
+        ///
+        /// In debug builds this panics if the generator's own RNG repeats its previous draw
+        /// within the same millisecond, which practically only happens if it is broken.
+        /// Caller-supplied RNGs, e.g. mocks passed to [`SulidGenerator::generate_with`],
+        /// are not checked.
+        fn next_random(&mut self, options: Options, timestamp_ms: u64) -> u128 {
+            let random = self.rng.gen::<u128>();
+            if cfg!(debug_assertions) {
+                let draw = Some((timestamp_ms, random));
+                assert!(
+                    self.last_draw != draw,
+                    "the random number generator repeated its previous value within one millisecond"
+                );
+                self.last_draw = draw;
+            }
+            self.layout_random(random, options, timestamp_ms)
+        }
+
+        /// Lays out the raw `random` value as the random component according to `options`.
+        fn layout_random(&mut self, random: u128, options: Options, timestamp_ms: u64) -> u128 {
+            let mut random = random & bitmask!(Sulid::RAND_BITS => u128);
+            if options.seq_bits > 0 {
+                if timestamp_ms == self.seq_ms {
+                    self.seq = self.seq.wrapping_add(1) & bitmask!(options.seq_bits => u128);
+                } else {
+                    self.seq_ms = timestamp_ms;
+                    self.seq = 0;
+                }
+                let nonce_bits = Sulid::RAND_BITS - options.seq_bits;
+                random = (self.seq << nonce_bits) | (random & bitmask!(nonce_bits => u128));
+            }
+            if !options.fill_reserved {
+                random &= !bitmask!(options.reserved_bits => u128);
+            }
+            random
+        }
+
+        /// Counts an emitted SULID stamped with `timestamp_ms` towards its millisecond.
+        fn record(&mut self, timestamp_ms: u64) {
+            if timestamp_ms == self.count_ms {
+                self.count += 1;
+            } else {
+                self.count_ms = timestamp_ms;
+                self.count = 1;
+            }
+        }
+    }
+
+    /// The number of candidates `generate` tries before giving up on a saturated dedup filter.
+    const MAX_DEDUP_ATTEMPTS: usize = 1000;
+
+    /// A bloom filter of generated SULIDs.
+    struct DedupFilter {
+        /// The bits of the filter.
+        words: Vec<u64>,
+    }
+
+    impl DedupFilter {
+        /// The number of bits set per SULID.
+        const HASHES: u64 = 4;
+
+        fn new(bits: usize) -> Self {
+            DedupFilter {
+                words: vec![0; bits.div_ceil(64)],
+            }
+        }
+
+        /// Adds `sulid` to the filter, returning whether it was newly inserted.
+        ///
+        /// Returns `false` if all its bits were set already, which is a collision or a
+        /// false positive.
+        fn insert(&mut self, sulid: Sulid) -> bool {
+            let value = sulid.u128();
+            let h1 = mix64(value as u64 ^ (value >> 64) as u64);
+            let h2 = mix64((value >> 64) as u64 ^ 0x9E37_79B9_7F4A_7C15) | 1;
+            let bits = self.words.len() as u64 * 64;
+            let mut inserted = false;
+            for i in 0..Self::HASHES {
+                let bit = h1.wrapping_add(i.wrapping_mul(h2)) % bits;
+                let (word, mask) = ((bit / 64) as usize, 1 << (bit % 64));
+                inserted |= self.words[word] & mask == 0;
+                self.words[word] |= mask;
+            }
+            inserted
+        }
+    }
+
+    /// The SplitMix64 finalizer.
+    fn mix64(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// The index to hand out to the next thread that generates with a thread-sharded generator.
+    ///
+    /// It saturates instead of wrapping, so it never drops below the number of indices
+    /// handed out.
+    static NEXT_THREAD_INDEX: AtomicU16 = AtomicU16::new(0);
+
+    thread_local! {
+        /// The index of the current thread among the threads using thread-sharded generators.
+        static THREAD_INDEX: u16 = NEXT_THREAD_INDEX
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |index| {
+                Some(index.saturating_add(1))
+            })
+            .unwrap_or(u16::MAX);
+    }
+
+    /// Returns the length of the longest run of equal characters in the string form of
+    /// `sulid` that includes at least one of the characters encoding the random component.
+    fn longest_random_run(sulid: &Sulid) -> usize {
+        /// The characters encoding the random component: after the 48-bit timestamp and
+        /// before the 10 worker bits.
+        const RANDOM_CHARS: std::ops::Range<usize> = 10..24;
+        let mut buffer = [0; ULID_LEN];
+        let chars = sulid.array_to_str(&mut buffer).as_bytes();
+        let mut longest = 0;
+        let mut start = 0;
+        for end in 1..=chars.len() {
+            if end == chars.len() || chars[end] != chars[start] {
+                if start < RANDOM_CHARS.end && end > RANDOM_CHARS.start {
+                    longest = longest.max(end - start);
+                }
+                start = end;
+            }
+        }
+        longest
+    }
+
+    /// Converts a datetime to `unit`s since the Unix epoch, truncated to 48 bits.
+    fn timestamp(datetime: SystemTime, unit: TimeUnit) -> u64 {
+        let timestamp = datetime
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_millis()
+            / unit.as_millis() as u128;
+        (timestamp & bitmask!(Sulid::TIME_BITS => u128)) as u64
+    }
+
+    /// An error returned by [`SulidGenerator::try_generate`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum GenError {
+        /// Another thread is generating at the moment.
+        WouldBlock,
+        /// A thread panicked while generating, leaving the state poisoned.
+        Poisoned,
+        /// The dedup filter rejected every candidate, see [`SulidGenerator::with_dedup`].
+        Saturated,
+    }
+
+    impl fmt::Display for GenError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self {
+                GenError::WouldBlock => "the generator is in use by another thread",
+                GenError::Poisoned => "the generator state was poisoned by a panic",
+                GenError::Saturated => "the dedup bloom filter is saturated",
+            })
+        }
+    }
+
+    impl std::error::Error for GenError {}
+
+    /// The sources of non-determinism used by [`SulidGenerator::generate_in`].
+    ///
+    /// Bundling the clock and the random number generator lets tests control all
+    /// non-deterministic inputs of a generator through a single value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::rngs::mock::StepRng;
+    /// use std::time::{Duration, SystemTime};
+    /// use sulid::{GenContext, SulidGenerator};
+    ///
+    /// let mut ctx = GenContext {
+    ///     clock: || SystemTime::UNIX_EPOCH + Duration::from_millis(42),
+    ///     rng: StepRng::new(7, 0),
+    /// };
+    /// let sulid = SulidGenerator::v2_new(1).generate_in(&mut ctx);
+    /// assert_eq!(sulid.timestamp_ms(), 42);
+    /// ```
+    pub struct GenContext<C, R> {
+        /// The clock used to read the current time.
+        pub clock: C,
+        /// The random number generator used for the random component.
+        pub rng: R,
+    }
+
+    /// A builder for configuring a [`SulidGenerator`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sulid::SulidGenerator;
+    /// let generator = SulidGenerator::v1_builder(1, 1)
+    ///     .sequence_bits(8)
+    ///     .reserved_bits(4)
+    ///     .fill_reserved(false)
+    ///     .build();
+    /// assert_eq!(generator.generate().random() & 0xF, 0);
+    /// ```
+    pub struct SulidGeneratorBuilder {
+        inner: InnerSulidGenerator,
+        options: Options,
+        seed: Option<[u8; 32]>,
+    }
+
+    impl SulidGeneratorBuilder {
+        /// Seeds the random number generator with the given bytes instead of system entropy.
+        pub fn seed_bytes(mut self, seed: [u8; 32]) -> Self {
+            self.seed = Some(seed);
+            self
+        }
+
+        /// Reserves the top `seq_bits` of the random component for a per-millisecond
+        /// sequence number.
+        ///
+        /// See [`SulidGenerator::v1_new_sequenced`] for details.
+        pub fn sequence_bits(mut self, seq_bits: u8) -> Self {
+            self.options.seq_bits = seq_bits;
+            self
+        }
+
+        /// Reserves the low `reserved_bits` of the random component for the application.
+        ///
+        /// The generator does not assign any meaning to these bits; how they are filled
+        /// is controlled by [`SulidGeneratorBuilder::fill_reserved`].
+        pub fn reserved_bits(mut self, reserved_bits: u8) -> Self {
+            self.options.reserved_bits = reserved_bits;
+            self
+        }
+
+        /// Sets whether the reserved bits are filled with randomness (the default) or zeros.
+        ///
+        /// Leaving the reserved bits as constant zeros makes the output deterministic in
+        /// those bits, but also lets an observer fingerprint the generator configuration
+        /// from the IDs it produces. Filling them with randomness avoids this and keeps
+        /// the full entropy of the random component until the application overwrites them.
+        pub fn fill_reserved(mut self, fill_reserved: bool) -> Self {
+            self.options.fill_reserved = fill_reserved;
+            self
+        }
+
+        /// Sets the unit of the generated timestamps, milliseconds by default.
+        ///
+        /// Coarser units stretch the lifespan of the 48-bit timestamp or match external
+        /// systems, but SULIDs within one unit are ordered randomly. The unit is not encoded
+        /// in the SULIDs, so they must be read with [`Sulid::datetime_with_unit`], and SULIDs
+        /// generated with different units are not comparable with each other or with
+        /// millisecond IDs. Methods referring to milliseconds, e.g.
+        /// [`SulidGenerator::generate_monotonic`], then work in this unit instead.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::{SulidGenerator, TimeUnit};
+        ///
+        /// let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_999);
+        /// let generator = SulidGenerator::v2_builder(1)
+        ///     .time_unit(TimeUnit::Seconds)
+        ///     .build()
+        ///     .with_clock(move || time);
+        /// let sulid = generator.generate();
+        /// assert_eq!(sulid.timestamp_ms(), 1_700_000_000);
+        /// assert_eq!(
+        ///     sulid.datetime_with_unit(TimeUnit::Seconds),
+        ///     SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        /// );
+        /// ```
+        pub fn time_unit(mut self, unit: TimeUnit) -> Self {
+            self.options.time_unit = unit;
+            self
+        }
+
+        /// Creates the configured SulidGenerator.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the sequence and reserved bits exceed 70 bits in total.
+        pub fn build(self) -> SulidGenerator {
+            assert!(
+                self.options.seq_bits <= Sulid::RAND_BITS,
+                "seq_bits must be in the range 0-70"
+            );
+            assert!(
+                self.options.seq_bits as u16 + self.options.reserved_bits as u16
+                    <= Sulid::RAND_BITS as u16,
+                "seq_bits and reserved_bits must not exceed 70 bits in total"
+            );
+            let rng = match self.seed {
+                Some(seed) => StdRng::from_seed(seed),
+                None => StdRng::from_entropy(),
+            };
+            let mut generator = SulidGenerator::with_rng(self.inner, rng);
+            generator.options = self.options;
+            generator
+        }
     }
 
     impl SulidGenerator {
@@ -194,9 +647,10 @@ mod std_feature {
         /// let generator = SulidGenerator::v1_new(1, 1);
         /// ```
         pub fn v1_new(data_center_id: u8, machine_id: u8) -> Self {
-            let inner = InnerSulidGenerator::v1_new(data_center_id, machine_id);
-            let rng = Mutex::new(StdRng::from_entropy());
-            SulidGenerator { inner, rng }
+            Self::with_rng(
+                InnerSulidGenerator::v1_new(data_center_id, machine_id),
+                StdRng::from_entropy(),
+            )
         }
 
         /// Creates a new SulidGenerator.
@@ -216,67 +670,1774 @@ mod std_feature {
         /// let generator = SulidGenerator::v2_new(1);
         /// ```
         pub fn v2_new(worker_id: u16) -> Self {
-            let inner = InnerSulidGenerator::v2_new(worker_id);
-            let rng = Mutex::new(StdRng::from_entropy());
-            SulidGenerator { inner, rng }
+            Self::with_rng(
+                InnerSulidGenerator::v2_new(worker_id),
+                StdRng::from_entropy(),
+            )
         }
 
-        /// Generates a new SULID.
+        /// Creates a new SulidGenerator whose random number generator is seeded with the given bytes.
         ///
-        /// This method generates a 128-bit unique identifier that combines
-        /// a timestamp, data center ID, machine ID, and a random component.
+        /// Generators created with the same seed and clock produce identical streams on every platform.
+        ///
+        /// # Arguments
+        ///
+        /// * `data_center_id` - A 5-bit identifier for the data center (0-31).
+        /// * `machine_id` - A 5-bit identifier for the machine within the data center (0-31).
+        /// * `seed` - The 32-byte seed passed to [`SeedableRng::from_seed`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `data_center_id` or `machine_id` is outside the 0-31 range.
         ///
         /// # Example
         ///
         /// ```
         /// use sulid::SulidGenerator;
-        /// let generator = SulidGenerator::v1_new(1, 1);
-        /// let sulid = generator.generate();
-        /// println!("Generated SULID 1: {}", sulid);
-        /// let generator = SulidGenerator::v2_new(1);
-        /// let sulid = generator.generate();
-        /// println!("Generated SULID 2: {}", sulid);
+        /// let generator = SulidGenerator::v1_new_with_seed_bytes(1, 1, [7; 32]);
         /// ```
-        #[inline]
-        pub fn generate(&self) -> Sulid {
-            let mut rng = self.rng.lock().unwrap();
-            match self.inner.0 {
-                Version::V1 {
-                    data_center_id,
-                    machine_id,
-                } => Sulid::v1_from_datetime_with_source(
-                    SystemTime::now(),
-                    &mut *rng,
-                    data_center_id,
-                    machine_id,
-                ),
-                Version::V2 { worker_id } => {
-                    Sulid::v2_from_datetime_with_source(SystemTime::now(), &mut *rng, worker_id)
-                }
-            }
+        pub fn v1_new_with_seed_bytes(data_center_id: u8, machine_id: u8, seed: [u8; 32]) -> Self {
+            Self::with_rng(
+                InnerSulidGenerator::v1_new(data_center_id, machine_id),
+                StdRng::from_seed(seed),
+            )
         }
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use super::*;
 
-        #[test]
-        /// Test that two generated SULIDs are unique.
-        fn generate_unique_ids() {
-            let generator = SulidGenerator::v1_new(1, 1);
+        /// Creates a new SulidGenerator whose random number generator is seeded with the given bytes.
+        ///
+        /// Generators created with the same seed and clock produce identical streams on every platform.
+        ///
+        /// # Arguments
+        ///
+        /// * `worker_id` - A 10-bit identifier combining data_center_id and machine_id (range: 0-1023).
+        /// * `seed` - The 32-byte seed passed to [`SeedableRng::from_seed`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `worker_id` is outside the 0-1023 range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new_with_seed_bytes(1, [7; 32]);
+        /// ```
+        pub fn v2_new_with_seed_bytes(worker_id: u16, seed: [u8; 32]) -> Self {
+            Self::with_rng(
+                InnerSulidGenerator::v2_new(worker_id),
+                StdRng::from_seed(seed),
+            )
+        }
 
-            let id1 = generator.generate();
-            let id2 = generator.generate();
+        /// Creates a new SulidGenerator that cycles through the given worker IDs.
+        ///
+        /// Each call to [`SulidGenerator::generate`] embeds the next worker ID of the list,
+        /// starting over after the last one, so a single generator can feed several logical
+        /// shards. All other generation methods use the first worker ID, and
+        /// [`SulidGenerator::owns`] accepts SULIDs of any worker in the list.
+        ///
+        /// SULIDs with different worker IDs are only ordered by their timestamps; within a
+        /// millisecond their order is random, so monotonicity across workers relies on the
+        /// clock advancing.
+        ///
+        /// # Arguments
+        ///
+        /// * `workers` - The 10-bit worker IDs to rotate through (range: 0-1023).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `workers` is empty or contains an ID outside the 0-1023 range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::with_worker_rotation(vec![1, 2]);
+        /// assert_eq!(generator.generate().v2_worker_id(), 1);
+        /// assert_eq!(generator.generate().v2_worker_id(), 2);
+        /// assert_eq!(generator.generate().v2_worker_id(), 1);
+        /// ```
+        pub fn with_worker_rotation(workers: Vec<u16>) -> Self {
+            assert!(!workers.is_empty(), "workers must not be empty");
+            assert!(
+                workers.iter().all(|&worker_id| worker_id < 1024),
+                "worker_id must be in the range 0-1023"
+            );
+            let mut generator = Self::v2_new(workers[0]);
+            generator.rotation = workers;
+            generator
+        }
 
-            assert_ne!(id1, id2);
+        /// Creates a new V2 SulidGenerator with a worker ID picked at random from `allowed`.
+        ///
+        /// When every process start picks a fresh worker ID, the IDs a node generates
+        /// across restarts don't share worker bits, so observers can't link them to the
+        /// same originating node. The set should be large enough, and disjoint from the
+        /// sets of other concurrently running processes, to keep the IDs unique.
+        ///
+        /// # Arguments
+        ///
+        /// * `allowed` - The 10-bit worker IDs to choose from (range: 0-1023).
+        /// * `rng` - The random number generator that picks the worker ID.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `allowed` is empty or the picked ID is outside the 0-1023 range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new_random_worker(&[10, 11, 12], &mut rand::thread_rng());
+        /// assert!((10..=12).contains(&generator.generate().v2_worker_id()));
+        /// ```
+        pub fn v2_new_random_worker<R: Rng + ?Sized>(allowed: &[u16], rng: &mut R) -> Self {
+            assert!(!allowed.is_empty(), "allowed must not be empty");
+            Self::v2_new(allowed[rng.gen_range(0..allowed.len())])
+        }
 
-            let generator = SulidGenerator::v2_new(1);
+        /// Creates a new SulidGenerator whose worker ID depends on the calling thread.
+        ///
+        /// Each thread that calls [`SulidGenerator::generate`] on a thread-sharded generator
+        /// is assigned a process-wide index on first use, in order of first use, and its
+        /// SULIDs embed the worker ID `(base_worker + index) % 1024`. Threads sharing one
+        /// generator thus never produce the same worker bits and cannot collide within a
+        /// millisecond, without any coordination beyond assigning the index. The
+        /// process should own the worker IDs from `base_worker` up to the number of threads
+        /// it runs.
+        ///
+        /// At most 1024 threads get distinct worker IDs; further threads wrap around and
+        /// share worker IDs with earlier ones, falling back to the random component for
+        /// uniqueness. Thread indices are never reused, so short-lived threads use them up
+        /// too. All other generation methods use `base_worker`. [`SulidGenerator::owns`]
+        /// accepts `base_worker` and the worker IDs of all threads that have been assigned
+        /// an index so far.
+        ///
+        /// # Arguments
+        ///
+        /// * `base_worker` - The 10-bit worker ID of the first thread (range: 0-1023).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `base_worker` is outside the 0-1023 range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new_thread_sharded(100);
+        /// let worker_id = generator.generate().v2_worker_id();
+        /// assert_eq!(generator.generate().v2_worker_id(), worker_id);
+        /// ```
+        pub fn v2_new_thread_sharded(base_worker: u16) -> Self {
+            let mut generator = Self::v2_new(base_worker);
+            generator.thread_sharded = true;
+            generator
+        }
 
-            let id1 = generator.generate();
-            let id2 = generator.generate();
+        /// Creates a generator for benchmarks that yields consecutive integers.
+        ///
+        /// Its [`SulidGenerator::generate`] returns `Sulid::from_u128(start)`, then
+        /// `start + 1` and so on, wrapping around after [`Sulid::MAX`], without reading the
+        /// clock or drawing random numbers. This gives cheap, reproducible input for
+        /// benchmarking downstream code. The other generation methods behave as for a
+        /// V2 generator with worker ID 0.
+        ///
+        /// **Not for production:** the SULIDs carry no timestamp, randomness or worker
+        /// identity, and two such generators produce the same SULIDs.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::{Sulid, SulidGenerator};
+        /// let generator = SulidGenerator::bench_counter(41);
+        /// assert_eq!(generator.generate(), Sulid::from_u128(41));
+        /// assert_eq!(generator.generate(), Sulid::from_u128(42));
+        /// ```
+        pub fn bench_counter(start: u128) -> Self {
+            let generator =
+                Self::with_rng(InnerSulidGenerator::v2_new(0), StdRng::from_seed([0; 32]));
+            generator.state.lock().unwrap().counter = Some(start);
+            generator
+        }
 
-            assert_ne!(id1, id2);
+        /// Creates a new SulidGenerator that embeds a per-millisecond sequence number.
+        ///
+        /// The top `seq_bits` of the 70-bit random component carry a counter that starts at
+        /// zero in each millisecond and increments with every generated SULID, so SULIDs
+        /// from the same generator and millisecond sort in generation order. The remaining
+        /// `70 - seq_bits` bits stay random.
+        ///
+        /// This reduces the entropy of each SULID by `seq_bits` bits. If more than
+        /// `2^seq_bits` SULIDs are generated within one millisecond, the counter wraps
+        /// around and uniqueness relies on the remaining random bits alone. Unlike
+        /// [`SulidGenerator::generate_monotonic`], this orders SULIDs without tracking the
+        /// previously generated one.
+        ///
+        /// # Arguments
+        ///
+        /// * `data_center_id` - A 5-bit identifier for the data center (0-31).
+        /// * `machine_id` - A 5-bit identifier for the machine within the data center (0-31).
+        /// * `seq_bits` - The number of bits reserved for the sequence number (0-70).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `data_center_id` or `machine_id` is outside the 0-31 range, or if
+        /// `seq_bits` exceeds 70.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v1_new_sequenced(1, 1, 16);
+        /// let sulid = generator.generate();
+        /// println!("Sequence number: {}", sulid.sequence(16));
+        /// ```
+        pub fn v1_new_sequenced(data_center_id: u8, machine_id: u8, seq_bits: u8) -> Self {
+            Self::v1_builder(data_center_id, machine_id)
+                .sequence_bits(seq_bits)
+                .build()
+        }
+
+        /// Creates a builder for a V1 SulidGenerator.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `data_center_id` or `machine_id` is outside the 0-31 range.
+        pub fn v1_builder(data_center_id: u8, machine_id: u8) -> SulidGeneratorBuilder {
+            SulidGeneratorBuilder {
+                inner: InnerSulidGenerator::v1_new(data_center_id, machine_id),
+                options: Options::default(),
+                seed: None,
+            }
+        }
+
+        /// Creates a builder for a V2 SulidGenerator.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `worker_id` is outside the 0-1023 range.
+        pub fn v2_builder(worker_id: u16) -> SulidGeneratorBuilder {
+            SulidGeneratorBuilder {
+                inner: InnerSulidGenerator::v2_new(worker_id),
+                options: Options::default(),
+                seed: None,
+            }
+        }
+
+        fn with_rng(inner: InnerSulidGenerator, rng: StdRng) -> Self {
+            SulidGenerator {
+                inner,
+                options: Options::default(),
+                state: Mutex::new(State {
+                    rng,
+                    last: Sulid::nil(),
+                    seq_ms: 0,
+                    seq: 0,
+                    rotation_index: 0,
+                    counter: None,
+                    last_draw: None,
+                    count_ms: 0,
+                    count: 0,
+                    dedup: None,
+                }),
+                clock: Box::new(now),
+                rotation: Vec::new(),
+                thread_sharded: false,
+            }
+        }
+
+        /// Replaces the clock used to timestamp generated SULIDs.
+        ///
+        /// This is mainly useful for tests that need a fixed or controllable time source.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::SulidGenerator;
+        ///
+        /// let generator = SulidGenerator::v1_new(1, 1)
+        ///     .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(42));
+        /// assert_eq!(generator.generate().timestamp_ms(), 42);
+        /// ```
+        pub fn with_clock<F>(mut self, clock: F) -> Self
+        where
+            F: Fn() -> SystemTime + Send + Sync + 'static,
+        {
+            self.clock = Box::new(clock);
+            self
+        }
+
+        /// Probes the resolution of the system clock in milliseconds.
+        ///
+        /// The system clock is read in a tight loop until it has advanced several times,
+        /// and the smallest observed step is returned. Most platforms report 1, but on
+        /// some, e.g. older Windows versions, the clock only advances every 10-16 ms. There
+        /// many SULIDs share a timestamp and their order within it is random, so
+        /// applications may want to switch to [`SulidGenerator::generate_monotonic`] or a
+        /// sequenced generator when the resolution is coarse.
+        ///
+        /// The probe busy-waits for a handful of clock steps, i.e. a few milliseconds on
+        /// fine-grained clocks and up to about 100 ms on coarse ones. The result can vary
+        /// between calls if the clock is adjusted meanwhile.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let resolution_ms = SulidGenerator::clock_resolution_ms();
+        /// assert!(resolution_ms >= 1);
+        /// ```
+        pub fn clock_resolution_ms() -> u64 {
+            const STEPS: usize = 5;
+            let mut last = timestamp(now(), TimeUnit::Millis);
+            let mut last_step = None;
+            let mut resolution_ms = u64::MAX;
+            let mut steps = 0;
+            while steps < STEPS {
+                let current = timestamp(now(), TimeUnit::Millis);
+                if current > last {
+                    // The first step only marks a tick boundary; later ones are full ticks.
+                    if let Some(last_step) = last_step {
+                        resolution_ms = resolution_ms.min(current - last_step);
+                        steps += 1;
+                    }
+                    last_step = Some(current);
+                } else if current < last {
+                    // The clock was set back, so restart from the new time.
+                    last_step = None;
+                } else {
+                    std::hint::spin_loop();
+                }
+                last = current;
+            }
+            resolution_ms
+        }
+
+        /// Enables deduplication of [`SulidGenerator::generate`] against a bloom filter.
+        ///
+        /// Every SULID is added to a filter of `bloom_bits` bits, and a candidate whose
+        /// bits are all set already is discarded and re-rolled. Duplicates of SULIDs this
+        /// generator emitted earlier are thus never emitted again, as a safety net against a
+        /// broken RNG or clock; other generators' SULIDs are not known to the filter.
+        ///
+        /// The filter takes `bloom_bits / 8` bytes and sets 4 bits per SULID. After `n`
+        /// SULIDs, a fresh candidate is a false positive and re-rolled with a probability
+        /// of about `(1 - e^(-4n / bloom_bits))^4`, e.g. 2.4% at `bloom_bits = 10n`. Size
+        /// the filter for the expected number of SULIDs: once it is saturated, re-rolls
+        /// become the norm, and after 1000 discarded candidates `generate` panics and
+        /// [`SulidGenerator::try_generate`] returns [`GenError::Saturated`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bloom_bits` is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// // 1 MiB, sized for about a million SULIDs.
+        /// let generator = SulidGenerator::v2_new(1).with_dedup(8 << 20);
+        /// assert_ne!(generator.generate(), generator.generate());
+        /// ```
+        pub fn with_dedup(self, bloom_bits: usize) -> Self {
+            assert!(bloom_bits > 0, "bloom_bits must not be zero");
+            self.state.lock().unwrap().dedup = Some(DedupFilter::new(bloom_bits));
+            self
+        }
+
+        /// Wraps the generator in an [`Arc`] so it can be shared between threads.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        ///
+        /// let generator = SulidGenerator::v2_new(1).into_shared();
+        /// let handle = {
+        ///     let generator = generator.clone();
+        ///     std::thread::spawn(move || generator.generate())
+        /// };
+        /// assert_ne!(handle.join().unwrap(), generator.generate());
+        /// ```
+        pub fn into_shared(self) -> Arc<SulidGenerator> {
+            Arc::new(self)
+        }
+
+        /// Generates a new SULID.
+        ///
+        /// This method generates a 128-bit unique identifier that combines
+        /// a timestamp, data center ID, machine ID, and a random component.
+        ///
+        /// In debug builds, this and the other generation methods panic when the
+        /// generator's own random number generator returns the same value twice in a row
+        /// within one millisecond, to catch a broken RNG early. Caller-supplied RNGs, e.g.
+        /// of [`SulidGenerator::generate_with`], are not checked. The check is compiled out
+        /// of release builds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v1_new(1, 1);
+        /// let sulid = generator.generate();
+        /// println!("Generated SULID 1: {}", sulid);
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid = generator.generate();
+        /// println!("Generated SULID 2: {}", sulid);
+        /// ```
+        #[inline]
+        pub fn generate(&self) -> Sulid {
+            let mut state = self.state.lock().unwrap();
+            self.generate_locked(&mut state)
+                .expect("the dedup bloom filter is saturated")
+        }
+
+        /// Generates a new SULID like [`SulidGenerator::generate`], but without blocking
+        /// or panicking on the internal lock.
+        ///
+        /// [`SulidGenerator::generate`] waits while another thread generates and panics if
+        /// a thread panicked while generating, which poisons the lock. This method returns
+        /// [`GenError::WouldBlock`] or [`GenError::Poisoned`] instead, for soft real-time
+        /// code that must neither block nor panic. The caller decides whether to retry.
+        /// Likewise, a saturated dedup filter yields [`GenError::Saturated`].
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid = generator.try_generate().unwrap();
+        /// assert!(generator.owns(&sulid));
+        /// ```
+        pub fn try_generate(&self) -> Result<Sulid, GenError> {
+            let mut state = match self.state.try_lock() {
+                Ok(state) => state,
+                Err(TryLockError::WouldBlock) => return Err(GenError::WouldBlock),
+                Err(TryLockError::Poisoned(_)) => return Err(GenError::Poisoned),
+            };
+            self.generate_locked(&mut state).ok_or(GenError::Saturated)
+        }
+
+        /// Generates a new SULID with the state already locked.
+        ///
+        /// With deduplication enabled, candidates already in the filter are re-rolled.
+        /// Returns `None` if the filter rejected all candidates.
+        fn generate_locked(&self, state: &mut State) -> Option<Sulid> {
+            for _ in 0..MAX_DEDUP_ATTEMPTS {
+                let sulid = self.next_candidate(state);
+                let accepted = match state.dedup.as_mut() {
+                    Some(filter) => filter.insert(sulid),
+                    None => true,
+                };
+                if accepted {
+                    if state.counter.is_none() {
+                        state.record(sulid.timestamp_ms());
+                    }
+                    return Some(sulid);
+                }
+            }
+            None
+        }
+
+        /// Generates a candidate SULID, before deduplication.
+        fn next_candidate(&self, state: &mut State) -> Sulid {
+            if let Some(counter) = state.counter {
+                state.counter = Some(counter.wrapping_add(1));
+                return Sulid::from_u128(counter);
+            }
+            let timestamp_ms = self.now_ms();
+            let random = state.next_random(self.options, timestamp_ms);
+            if let Some(&worker_id) = self.rotation.get(state.rotation_index) {
+                state.rotation_index = (state.rotation_index + 1) % self.rotation.len();
+                return Sulid::v2_from_parts(timestamp_ms, random, worker_id);
+            }
+            let sulid = self.build(timestamp_ms, random);
+            if self.thread_sharded {
+                let base_worker = sulid.v2_worker_id();
+                let worker_id = THREAD_INDEX.with(|index| base_worker.wrapping_add(*index));
+                return sulid.v2_with_worker_id(worker_id);
+            }
+            sulid
+        }
+
+        /// Checks whether the worker bits of the given SULID match this generator's
+        /// data center and machine IDs (V1) or worker ID (V2).
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(7);
+        /// assert!(generator.owns(&generator.generate()));
+        /// assert!(!SulidGenerator::v2_new(8).owns(&generator.generate()));
+        /// ```
+        ///
+        /// Generators with a worker rotation accept all workers of the rotation, and
+        /// thread-sharded generators `base_worker` and the worker IDs of all threads with
+        /// an index.
+        #[inline]
+        pub fn owns(&self, sulid: &Sulid) -> bool {
+            if let (true, &Version::V2 { worker_id }) = (self.thread_sharded, &self.inner.0) {
+                let offset = sulid.v2_worker_id().wrapping_sub(worker_id)
+                    & bitmask!(Sulid::WORKER_BITS => u16);
+                let accepted = NEXT_THREAD_INDEX.load(Ordering::Relaxed).clamp(1, 1024);
+                return offset < accepted;
+            }
+            self.inner.owns(sulid) || self.rotation.contains(&sulid.v2_worker_id())
+        }
+
+        /// Returns the worker configuration of this generator.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::{SulidGenerator, SulidVersion};
+        /// let generator1 = SulidGenerator::v2_new(1);
+        /// let generator2 = SulidGenerator::v2_new(2);
+        /// assert_ne!(generator1.worker_identity(), generator2.worker_identity());
+        /// assert_eq!(generator1.worker_identity().version(), SulidVersion::V2);
+        /// ```
+        #[inline]
+        pub fn worker_identity(&self) -> WorkerIdentity {
+            self.inner.worker_identity()
+        }
+
+        /// Generates a new SULID whose string form has no run of the same character longer
+        /// than `max_run`.
+        ///
+        /// Long runs such as `0000` are easy to miscount when an ID is read aloud or
+        /// transcribed. The random component is re-rolled until every run that involves
+        /// one of its characters is at most `max_run` long. Runs entirely within the
+        /// timestamp characters (the first 10) or the worker characters (the last 2) are
+        /// fixed for a given millisecond and generator and are therefore not considered.
+        ///
+        /// Each re-roll costs one more [`SulidGenerator::generate`] call. With `max_run`
+        /// of 2 or more, re-rolls are rare; with a `max_run` of 1 almost 40% of the
+        /// candidates are rejected.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `max_run` is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid = generator.generate_readable(2);
+        /// assert!(!sulid.to_string()[10..24].contains("000"));
+        /// ```
+        pub fn generate_readable(&self, max_run: usize) -> Sulid {
+            assert!(max_run > 0, "max_run must be at least 1");
+            loop {
+                let sulid = self.generate();
+                if longest_random_run(&sulid) <= max_run {
+                    return sulid;
+                }
+            }
+        }
+
+        /// Generates a new SULID carrying a checksum for corruption detection.
+        ///
+        /// This is [`SulidGenerator::generate`] followed by [`Sulid::with_checksum`], so the
+        /// low 8 bits of the random component hold the checksum instead of randomness and
+        /// any reserved or sequence bits in them are overwritten. See
+        /// [`Sulid::with_checksum`] for the entropy tradeoff.
+        ///
+        /// This method requires the `checksum` feature.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// assert!(generator.generate_checksummed().verify_checksum());
+        /// ```
+        #[cfg(feature = "checksum")]
+        pub fn generate_checksummed(&self) -> Sulid {
+            self.generate().with_checksum()
+        }
+
+        /// Generates a new SULID and returns it together with the clock reading it was
+        /// stamped with.
+        ///
+        /// The returned time has the clock's full precision, while the SULID's
+        /// [`Sulid::datetime`] is truncated to milliseconds. Callers that need both, e.g.
+        /// a span's ID and start time, don't have to read the clock a second time.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::time::Duration;
+        /// use sulid::SulidGenerator;
+        ///
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let (sulid, time) = generator.generate_with_time();
+        /// let elapsed = time.duration_since(sulid.datetime()).unwrap();
+        /// assert!(elapsed < Duration::from_millis(1));
+        /// ```
+        pub fn generate_with_time(&self) -> (Sulid, SystemTime) {
+            let time = (self.clock)();
+            let timestamp_ms = timestamp(time, self.options.time_unit);
+            let mut state = self.state.lock().unwrap();
+            let random = state.next_random(self.options, timestamp_ms);
+            state.record(timestamp_ms);
+            (self.build(timestamp_ms, random), time)
+        }
+
+        /// Generates a new SULID with an application-supplied token in place of the
+        /// timestamp.
+        ///
+        /// The token occupies the 48-bit timestamp slot, so SULIDs with larger tokens sort
+        /// after SULIDs with smaller ones, regardless of the wall-clock time at which they
+        /// were generated. This lets e.g. a coordinator's monotonic sequence dominate the
+        /// ordering. The random component and worker identity are filled in as by
+        /// [`SulidGenerator::generate`]. Any bits of `token` beyond the low 48 are
+        /// discarded.
+        ///
+        /// Since the timestamp slot no longer holds a time, [`Sulid::datetime`] and
+        /// [`Sulid::timestamp_ms`] of the returned SULID are meaningless; the latter
+        /// returns the token.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid1 = generator.generate_with_token(1);
+        /// let sulid2 = generator.generate_with_token(2);
+        /// assert!(sulid1 < sulid2);
+        /// assert_eq!(sulid2.timestamp_ms(), 2);
+        /// ```
+        pub fn generate_with_token(&self, token: u64) -> Sulid {
+            let token = token & bitmask!(Sulid::TIME_BITS => u64);
+            let mut state = self.state.lock().unwrap();
+            let random = state.next_random(self.options, token);
+            self.build(token, random)
+        }
+
+        /// Generates a new SULID using the given random number generator instead of the
+        /// generator's own.
+        ///
+        /// The generator's clock, worker identity and options still apply.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use rand::rngs::mock::StepRng;
+        /// use sulid::SulidGenerator;
+        ///
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let mut rng = StepRng::new(7, 0);
+        /// assert_eq!(generator.generate_with(&mut rng).random(), (7 << 64) | 7);
+        /// ```
+        pub fn generate_with<R: Rng>(&self, rng: &mut R) -> Sulid {
+            let timestamp_ms = self.now_ms();
+            let random = rng.gen::<u128>();
+            let mut state = self.state.lock().unwrap();
+            let random = state.layout_random(random, self.options, timestamp_ms);
+            state.record(timestamp_ms);
+            self.build(timestamp_ms, random)
+        }
+
+        /// Generates a new SULID using the clock and random number generator of `ctx`
+        /// instead of the generator's own.
+        ///
+        /// The generator's worker identity and options still apply.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use rand::rngs::mock::StepRng;
+        /// use std::time::SystemTime;
+        /// use sulid::{GenContext, SulidGenerator};
+        ///
+        /// let generator = SulidGenerator::v1_new(1, 1);
+        /// let mut ctx = GenContext {
+        ///     clock: SystemTime::now,
+        ///     rng: StepRng::new(0, 1),
+        /// };
+        /// let sulid1 = generator.generate_in(&mut ctx);
+        /// let sulid2 = generator.generate_in(&mut ctx);
+        /// assert_ne!(sulid1, sulid2);
+        /// ```
+        pub fn generate_in<C, R>(&self, ctx: &mut GenContext<C, R>) -> Sulid
+        where
+            C: FnMut() -> SystemTime,
+            R: Rng,
+        {
+            let timestamp_ms = timestamp((ctx.clock)(), self.options.time_unit);
+            let random = ctx.rng.gen::<u128>();
+            let mut state = self.state.lock().unwrap();
+            let random = state.layout_random(random, self.options, timestamp_ms);
+            self.build(timestamp_ms, random)
+        }
+
+        /// Generates a new SULID that is strictly greater than `reference`.
+        ///
+        /// If the clock is past the reference's millisecond, this generates a SULID like
+        /// [`SulidGenerator::generate`]. Otherwise, e.g. when the reference comes from a
+        /// clock that is ahead, the reference's timestamp is reused and its random component
+        /// incremented by one. If the random component is already at its maximum, the
+        /// next millisecond is used with a zero random component instead.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the reference has the largest timestamp and random component, so no
+        /// greater SULID exists.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::{Sulid, SulidGenerator};
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let reference = Sulid::v2_from_parts(u64::MAX >> 16, 0, 1023);
+        /// assert!(generator.generate_after(&reference) > reference);
+        /// ```
+        pub fn generate_after(&self, reference: &Sulid) -> Sulid {
+            let timestamp_ms = self.now_ms();
+            if timestamp_ms > reference.timestamp_ms() {
+                let mut state = self.state.lock().unwrap();
+                let random = state.next_random(self.options, timestamp_ms);
+                state.record(timestamp_ms);
+                return self.build(timestamp_ms, random);
+            }
+            if reference.random() < bitmask!(Sulid::RAND_BITS => u128) {
+                return self.build(reference.timestamp_ms(), reference.random() + 1);
+            }
+            assert!(
+                reference.timestamp_ms() < bitmask!(Sulid::TIME_BITS => u64),
+                "no SULID is greater than the reference"
+            );
+            self.build(reference.timestamp_ms() + 1, 0)
+        }
+
+        /// Generates a new SULID that is strictly greater than the previous one
+        /// produced by monotonic generation.
+        ///
+        /// If the clock has not advanced past the timestamp of the previous SULID
+        /// (including when it moved backwards), the previous SULID's random
+        /// component is incremented instead of drawing a new one.
+        ///
+        /// Returns `None` if the random component is exhausted within the current
+        /// millisecond.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v1_new(1, 1);
+        /// let sulid1 = generator.generate_monotonic().unwrap();
+        /// let sulid2 = generator.generate_monotonic().unwrap();
+        /// assert!(sulid1 < sulid2);
+        /// ```
+        pub fn generate_monotonic(&self) -> Option<Sulid> {
+            let timestamp_ms = self.now_ms();
+            let mut state = self.state.lock().unwrap();
+            let next = self.next_monotonic(timestamp_ms, &mut state)?;
+            state.last = next;
+            state.record(next.timestamp_ms());
+            Some(next)
+        }
+
+        /// Generates a new SULID like [`SulidGenerator::generate_monotonic`], but
+        /// waits for the clock to move past the previous SULID's millisecond instead
+        /// of failing when the random component is exhausted.
+        ///
+        /// The wait yields the current thread between clock reads. In the worst case it
+        /// lasts until the next millisecond, or, if the clock moved backwards, until the
+        /// clock catches up with the previous SULID's timestamp.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid1 = generator.generate_monotonic_blocking();
+        /// let sulid2 = generator.generate_monotonic_blocking();
+        /// assert!(sulid1 < sulid2);
+        /// ```
+        pub fn generate_monotonic_blocking(&self) -> Sulid {
+            loop {
+                if let Some(sulid) = self.generate_monotonic() {
+                    return sulid;
+                }
+                std::thread::yield_now();
+            }
+        }
+
+        /// Generates a new SULID like [`SulidGenerator::generate_monotonic_blocking`], but
+        /// waits asynchronously instead of yielding the thread.
+        ///
+        /// When the random component is exhausted, this sleeps for a millisecond with
+        /// [`tokio::time::sleep`] before retrying, so the async runtime can run other tasks
+        /// in the meantime. It must be awaited within a Tokio runtime with the time driver
+        /// enabled.
+        ///
+        /// This method requires the `tokio` feature.
+        #[cfg(feature = "tokio")]
+        pub async fn generate_monotonic_async(&self) -> Sulid {
+            loop {
+                if let Some(sulid) = self.generate_monotonic() {
+                    return sulid;
+                }
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        }
+
+        /// Generates `count` unique SULIDs in parallel on the rayon thread pool.
+        ///
+        /// All SULIDs share one clock reading and the generator's worker identity. The
+        /// work is split into at most 65,536 chunks, one per rayon thread, and uniqueness
+        /// is guaranteed by giving every chunk a distinct range of random values: the top
+        /// 16 bits of the random component hold the chunk index, and within a chunk the
+        /// remaining 54 bits count up from a random start below 2^53, drawn from a per-chunk
+        /// RNG seeded by this generator. The sequence and reserved bit options are not
+        /// applied.
+        ///
+        /// The result is not sorted. Uniqueness only holds within the batch; SULIDs from
+        /// other calls are as unlikely to collide as with [`SulidGenerator::generate`].
+        ///
+        /// This method requires the `rayon` feature.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let ids = generator.generate_batch_parallel(1000);
+        /// assert_eq!(ids.len(), 1000);
+        /// ```
+        #[cfg(feature = "rayon")]
+        pub fn generate_batch_parallel(&self, count: usize) -> Vec<Sulid> {
+            use rayon::prelude::*;
+
+            const CHUNK_BITS: u8 = 16;
+            const OFFSET_BITS: u8 = Sulid::RAND_BITS - CHUNK_BITS;
+
+            let timestamp_ms = self.now_ms();
+            let chunk_count = rayon::current_num_threads().clamp(1, 1 << CHUNK_BITS);
+            let chunk_len = count.div_ceil(chunk_count).max(1);
+            let seeds: Vec<[u8; 32]> = {
+                let mut state = self.state.lock().unwrap();
+                (0..chunk_count).map(|_| state.rng.gen()).collect()
+            };
+
+            let mut ids = vec![Sulid::nil(); count];
+            ids.par_chunks_mut(chunk_len)
+                .zip(seeds)
+                .enumerate()
+                .for_each(|(index, (chunk, seed))| {
+                    let mut rng = StdRng::from_seed(seed);
+                    let start = rng.gen::<u128>() & bitmask!(OFFSET_BITS - 1 => u128);
+                    let prefix = (index as u128) << OFFSET_BITS;
+                    for (offset, id) in chunk.iter_mut().enumerate() {
+                        *id = self.build(timestamp_ms, prefix | (start + offset as u128));
+                    }
+                });
+            ids
+        }
+
+        /// Clears the monotonic and sequence state, so the next SULID starts fresh from
+        /// the current clock reading.
+        ///
+        /// Monotonic generation never goes below the previous SULID, so after the clock
+        /// was corrected backwards the generated timestamps can stay ahead of wall time.
+        /// Resetting drops that high-water mark.
+        ///
+        /// This is only safe when no SULID generated after the reset may collide with or
+        /// need to sort after the SULIDs generated before it. In particular, if the clock
+        /// is behind the previous SULID's timestamp, the following SULIDs reuse already
+        /// covered keyspace and are no longer greater than the earlier ones. Sequence
+        /// numbers restart from zero as well.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(1);
+        /// let sulid1 = generator.generate_monotonic().unwrap();
+        /// generator.reset();
+        /// let sulid2 = generator.generate_monotonic().unwrap();
+        /// assert!(sulid2.timestamp_ms() >= sulid1.timestamp_ms());
+        /// ```
+        pub fn reset(&self) {
+            let mut state = self.state.lock().unwrap();
+            state.last = Sulid::nil();
+            state.seq_ms = 0;
+            state.seq = 0;
+            state.count_ms = 0;
+            state.count = 0;
+        }
+
+        /// Returns how many SULIDs this generator has stamped with the current millisecond.
+        ///
+        /// SULIDs are counted by the millisecond embedded in them, including those from
+        /// monotonic generation, so a value approaching the per-millisecond budget, e.g.
+        /// `2^seq_bits` for a sequenced generator, signals burst pressure. Returns zero if
+        /// the last SULID was stamped with another millisecond than the clock shows now.
+        /// Only SULIDs stamped with this generator's clock are counted, not benchmark
+        /// counter SULIDs, tokens of [`SulidGenerator::generate_with_token`], SULIDs stamped
+        /// by the clock of a [`GenContext`], candidates discarded by deduplication, or SULIDs
+        /// derived from a reference without a fresh draw by
+        /// [`SulidGenerator::generate_after`].
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::SulidGenerator;
+        ///
+        /// let now = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        /// let generator = SulidGenerator::v2_new(1).with_clock(move || now);
+        /// generator.generate();
+        /// generator.generate();
+        /// assert_eq!(generator.current_ms_count(), 2);
+        /// ```
+        pub fn current_ms_count(&self) -> u64 {
+            let timestamp_ms = self.now_ms();
+            let state = self.state.lock().unwrap();
+            if state.count_ms == timestamp_ms {
+                state.count
+            } else {
+                0
+            }
+        }
+
+        /// Generates `count` monotonic SULIDs and writes them to `w`, one per line.
+        ///
+        /// Each line contains only the canonical string of a SULID and there is no header
+        /// line. The SULIDs are generated with
+        /// [`SulidGenerator::generate_monotonic_blocking`] and written as they are
+        /// generated, so memory usage doesn't grow with `count`. Wrap `w` in a
+        /// [`std::io::BufWriter`] when writing to a file or socket.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v1_new(1, 1);
+        /// let mut out = Vec::new();
+        /// generator.write_csv(&mut out, 3).unwrap();
+        /// assert_eq!(String::from_utf8(out).unwrap().lines().count(), 3);
+        /// ```
+        pub fn write_csv<W: Write + ?Sized>(&self, w: &mut W, count: usize) -> io::Result<()> {
+            for _ in 0..count {
+                writeln!(w, "{}", self.generate_monotonic_blocking())?;
+            }
+            Ok(())
+        }
+
+        /// Like [`SulidGenerator::write_csv`], but adds the timestamp and worker ID of each
+        /// SULID as extra columns.
+        ///
+        /// Each line has the format `sulid,timestamp_ms,worker_id`, where `timestamp_ms` is
+        /// the embedded Unix timestamp in milliseconds and `worker_id` the combined 10-bit
+        /// worker field, both in decimal.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use sulid::SulidGenerator;
+        /// let generator = SulidGenerator::v2_new(7);
+        /// let mut out = Vec::new();
+        /// generator.write_csv_with_parts(&mut out, 1).unwrap();
+        /// assert!(String::from_utf8(out).unwrap().ends_with(",7\n"));
+        /// ```
+        pub fn write_csv_with_parts<W: Write + ?Sized>(
+            &self,
+            w: &mut W,
+            count: usize,
+        ) -> io::Result<()> {
+            for _ in 0..count {
+                let sulid = self.generate_monotonic_blocking();
+                writeln!(
+                    w,
+                    "{},{},{}",
+                    sulid,
+                    sulid.timestamp_ms(),
+                    sulid.v2_worker_id()
+                )?;
+            }
+            Ok(())
+        }
+
+        fn next_monotonic(&self, timestamp_ms: u64, state: &mut State) -> Option<Sulid> {
+            let last = state.last;
+            if timestamp_ms <= last.timestamp_ms() {
+                return last.increment();
+            }
+            let random = state.next_random(self.options, timestamp_ms);
+            Some(self.build(timestamp_ms, random))
+        }
+
+        /// Reads the clock in the configured unit since the Unix epoch, truncated to 48 bits.
+        fn now_ms(&self) -> u64 {
+            timestamp((self.clock)(), self.options.time_unit)
+        }
+
+        /// Assembles a SULID with this generator's worker identity.
+        fn build(&self, timestamp_ms: u64, random: u128) -> Sulid {
+            match self.inner.0 {
+                Version::V1 {
+                    data_center_id,
+                    machine_id,
+                } => Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id),
+                Version::V2 { worker_id } => Sulid::v2_from_parts(timestamp_ms, random, worker_id),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) mod tests {
+        use super::*;
+        use std::sync::atomic::AtomicU64;
+
+        /// Returns a clock reading the milliseconds stored in the returned atomic.
+        pub(crate) fn controllable_clock(
+            start_ms: u64,
+        ) -> (
+            impl Fn() -> SystemTime + Send + Sync + 'static,
+            Arc<AtomicU64>,
+        ) {
+            let ms = Arc::new(AtomicU64::new(start_ms));
+            let clock_ms = Arc::clone(&ms);
+            let clock = move || {
+                SystemTime::UNIX_EPOCH + Duration::from_millis(clock_ms.load(Ordering::SeqCst))
+            };
+            (clock, ms)
+        }
+
+        #[test]
+        /// Test that two generated SULIDs are unique.
+        fn generate_unique_ids() {
+            let generator = SulidGenerator::v1_new(1, 1);
+
+            let id1 = generator.generate();
+            let id2 = generator.generate();
+
+            assert_ne!(id1, id2);
+
+            let generator = SulidGenerator::v2_new(1);
+
+            let id1 = generator.generate();
+            let id2 = generator.generate();
+
+            assert_ne!(id1, id2);
+        }
+
+        #[test]
+        /// Test that every 10-bit worker ID is accepted.
+        fn v2_worker_id_full_range() {
+            let generator = SulidGenerator::v2_new(1023);
+            assert_eq!(generator.generate().v2_worker_id(), 1023);
+        }
+
+        #[test]
+        #[should_panic(expected = "worker_id must be in the range 0-1023")]
+        /// Test that creating a SulidGenerator with an out-of-range worker_id panics.
+        fn v2_worker_id_out_of_range() {
+            let _ = SulidGenerator::v2_new(1024);
+        }
+
+        #[test]
+        /// Test that generators with identical seeds and clocks produce identical output.
+        fn seed_bytes_are_deterministic() {
+            let clock =
+                || SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1_700_000_000_000);
+            let seed = [42u8; 32];
+            let generator1 = SulidGenerator::v1_new_with_seed_bytes(1, 2, seed).with_clock(clock);
+            let generator2 = SulidGenerator::v1_new_with_seed_bytes(1, 2, seed).with_clock(clock);
+
+            for _ in 0..8 {
+                assert_eq!(generator1.generate(), generator2.generate());
+            }
+
+            let generator3 =
+                SulidGenerator::v1_new_with_seed_bytes(1, 2, [43u8; 32]).with_clock(clock);
+            assert_ne!(generator1.generate(), generator3.generate());
+        }
+
+        #[test]
+        /// Test that monotonic generation increments within a millisecond.
+        fn generate_monotonic_increments() {
+            let clock = || SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+            let generator = SulidGenerator::v1_new(1, 1).with_clock(clock);
+
+            let id1 = generator.generate_monotonic().unwrap();
+            let id2 = generator.generate_monotonic().unwrap();
+            assert_eq!(id1.timestamp_ms(), id2.timestamp_ms());
+            assert_eq!(id1.random() + 1, id2.random());
+
+            generator.state.lock().unwrap().last =
+                Sulid::v1_from_parts(1_700_000_000_000, bitmask!(Sulid::RAND_BITS => u128), 1, 1);
+            assert_eq!(generator.generate_monotonic(), None);
+        }
+
+        #[test]
+        /// Test that blocking monotonic generation rolls over to the next millisecond on exhaustion.
+        fn generate_monotonic_blocking_rolls_over() {
+            use std::sync::atomic::{AtomicU64, Ordering};
+            use std::sync::Arc;
+
+            const START_MS: u64 = 1_700_000_000_000;
+            let reads = Arc::new(AtomicU64::new(0));
+            let clock_reads = Arc::clone(&reads);
+            // The clock stays on START_MS for the first three reads, then advances.
+            let clock = move || {
+                let advanced = clock_reads.fetch_add(1, Ordering::SeqCst) >= 3;
+                SystemTime::UNIX_EPOCH + Duration::from_millis(START_MS + advanced as u64)
+            };
+            let generator = SulidGenerator::v2_new(7).with_clock(clock);
+            let exhausted = Sulid::v2_from_parts(START_MS, bitmask!(Sulid::RAND_BITS => u128), 7);
+            generator.state.lock().unwrap().last = exhausted;
+
+            let sulid = generator.generate_monotonic_blocking();
+            assert!(sulid > exhausted);
+            assert_eq!(sulid.timestamp_ms(), START_MS + 1);
+            assert_eq!(sulid.v2_worker_id(), 7);
+            assert!(reads.load(Ordering::SeqCst) > 3);
+        }
+
+        #[test]
+        /// Test that the sequence number increments within a millisecond and resets across milliseconds.
+        fn sequenced_generation() {
+            let (clock, ms) = controllable_clock(1_700_000_000_000);
+            let generator = SulidGenerator::v1_new_sequenced(1, 2, 12).with_clock(clock);
+
+            let ids: Vec<_> = (0..5).map(|_| generator.generate()).collect();
+            for (i, id) in ids.iter().enumerate() {
+                assert_eq!(id.sequence(12), i as u128);
+                assert_eq!((id.v1_data_center_id(), id.v1_machine_id()), (1, 2));
+            }
+            assert!(crate::is_monotonic(&ids));
+
+            ms.fetch_add(1, Ordering::SeqCst);
+            let id = generator.generate();
+            assert_eq!(id.sequence(12), 0);
+            assert!(id > ids[4]);
+            assert_eq!(generator.generate().sequence(12), 1);
+        }
+
+        #[test]
+        /// Test that the counter strictly increases across many SULIDs in one millisecond.
+        fn counter_generation() {
+            let stamp = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+            let generator = SulidGenerator::v1_new_sequenced(3, 4, 10).with_clock(move || stamp);
+
+            let ids: Vec<_> = (0..1024).map(|_| generator.generate()).collect();
+            for pair in ids.windows(2) {
+                assert!(pair[0].sequence(10) < pair[1].sequence(10));
+                assert!(pair[0] < pair[1]);
+            }
+            assert_eq!(ids[1023].sequence(10), 1023);
+        }
+
+        #[test]
+        /// Test that reserved bits are random by default and zero when not filled.
+        fn fill_reserved_bits() {
+            const RESERVED_BITS: u8 = 8;
+            let reserved = |sulid: Sulid| sulid.random() & bitmask!(RESERVED_BITS => u128);
+
+            let generator = SulidGenerator::v2_builder(1)
+                .reserved_bits(RESERVED_BITS)
+                .build();
+            let values: std::collections::HashSet<_> =
+                (0..64).map(|_| reserved(generator.generate())).collect();
+            assert!(values.len() > 1);
+
+            let generator = SulidGenerator::v2_builder(1)
+                .sequence_bits(4)
+                .reserved_bits(RESERVED_BITS)
+                .fill_reserved(false)
+                .build();
+            for _ in 0..64 {
+                let sulid = generator.generate();
+                assert_eq!(reserved(sulid), 0);
+                assert_eq!(sulid.v2_worker_id(), 1);
+            }
+        }
+
+        #[cfg(feature = "tokio")]
+        #[tokio::test]
+        /// Test that async monotonic generation awaits the next millisecond on exhaustion.
+        async fn generate_monotonic_async_rolls_over() {
+            use std::sync::atomic::{AtomicU64, Ordering};
+            use std::sync::Arc;
+
+            const START_MS: u64 = 1_700_000_000_000;
+            let reads = Arc::new(AtomicU64::new(0));
+            let clock_reads = Arc::clone(&reads);
+            // The clock stays on START_MS for the first five reads, then advances.
+            let clock = move || {
+                let advanced = clock_reads.fetch_add(1, Ordering::SeqCst) >= 5;
+                SystemTime::UNIX_EPOCH + Duration::from_millis(START_MS + advanced as u64)
+            };
+            let generator = SulidGenerator::v1_new(3, 4).with_clock(clock);
+            let almost_exhausted =
+                Sulid::v1_from_parts(START_MS, bitmask!(Sulid::RAND_BITS => u128) - 1, 3, 4);
+            generator.state.lock().unwrap().last = almost_exhausted;
+
+            let mut ids = vec![almost_exhausted];
+            for _ in 0..3 {
+                ids.push(generator.generate_monotonic_async().await);
+            }
+            assert!(crate::is_monotonic(&ids));
+            assert_eq!(ids[1].timestamp_ms(), START_MS);
+            assert_eq!(ids[2].timestamp_ms(), START_MS + 1);
+            assert!(reads.load(Ordering::SeqCst) > 5);
+        }
+
+        #[test]
+        /// Test that a generator recognizes SULIDs carrying its worker identity.
+        fn owns_matching_ids() {
+            let generator = SulidGenerator::v1_new(3, 5);
+            assert!(generator.owns(&generator.generate()));
+            assert!(generator.owns(&Sulid::v1_from_parts(1, 1, 3, 5)));
+            assert!(!generator.owns(&Sulid::v1_from_parts(1, 1, 5, 3)));
+            assert!(!SulidGenerator::v1_new(3, 6).owns(&generator.generate()));
+
+            let generator = SulidGenerator::v2_new(1000);
+            assert!(generator.owns(&generator.generate()));
+            assert!(generator.owns(&Sulid::v2_from_parts(1, 1, 1000)));
+            assert!(!generator.owns(&Sulid::v2_from_parts(1, 1, 999)));
+        }
+
+        #[test]
+        /// Test that CSV output parses back into the generated SULIDs.
+        fn write_csv_round_trip() {
+            let generator = SulidGenerator::v1_new(2, 3);
+            let mut out = Vec::new();
+            generator.write_csv(&mut out, 5).unwrap();
+            let ids: Vec<Sulid> = String::from_utf8(out)
+                .unwrap()
+                .lines()
+                .map(|line| line.parse().unwrap())
+                .collect();
+            assert_eq!(ids.len(), 5);
+            assert!(crate::is_monotonic(&ids));
+            assert!(ids.iter().all(|id| generator.owns(id)));
+
+            let mut out = Vec::new();
+            generator.write_csv_with_parts(&mut out, 5).unwrap();
+            let text = String::from_utf8(out).unwrap();
+            for line in text.lines() {
+                let columns: Vec<_> = line.split(',').collect();
+                let sulid: Sulid = columns[0].parse().unwrap();
+                assert_eq!(columns[1], sulid.timestamp_ms().to_string());
+                assert_eq!(columns[2], crate::combine_worker_id(2, 3).to_string());
+                assert!(sulid > ids[4]);
+            }
+            assert_eq!(text.lines().count(), 5);
+        }
+
+        #[test]
+        /// Test that a fixed clock and a StepRng in a GenContext produce exact output.
+        fn generate_in_context() {
+            use rand::rngs::mock::StepRng;
+
+            let generator = SulidGenerator::v1_new(1, 2);
+            let mut ctx = GenContext {
+                clock: || SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000),
+                rng: StepRng::new(5, 1),
+            };
+
+            // A u128 is drawn from two consecutive u64 values, low half first.
+            let sulid = generator.generate_in(&mut ctx);
+            assert_eq!(
+                sulid,
+                Sulid::v1_from_parts(1_700_000_000_000, (6 << 64) | 5, 1, 2)
+            );
+            let sulid = generator.generate_in(&mut ctx);
+            assert_eq!(
+                sulid,
+                Sulid::v1_from_parts(1_700_000_000_000, (8 << 64) | 7, 1, 2)
+            );
+        }
+
+        #[test]
+        /// Test that reset drops the monotonic high-water mark.
+        fn reset_restarts_from_clock() {
+            let generator = SulidGenerator::v2_new(1)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_000));
+            let ahead = Sulid::v2_from_parts(5_000, 0, 1);
+            generator.state.lock().unwrap().last = ahead;
+
+            let sulid = generator.generate_monotonic().unwrap();
+            assert_eq!(sulid.timestamp_ms(), 5_000);
+            assert!(sulid > ahead);
+
+            generator.reset();
+            let sulid1 = generator.generate_monotonic().unwrap();
+            let sulid2 = generator.generate_monotonic().unwrap();
+            assert_eq!(sulid1.timestamp_ms(), 1_000);
+            assert!(sulid1 < sulid2);
+            assert!(generator.owns(&sulid2));
+        }
+
+        #[test]
+        /// Test that tokens, not the wall clock, determine the order.
+        fn generate_with_token_orders_by_token() {
+            let (clock, millis) = controllable_clock(2_000);
+            let generator = SulidGenerator::v1_new(3, 4).with_clock(clock);
+
+            let sulid1 = generator.generate_with_token(10);
+            millis.store(1_000, Ordering::SeqCst);
+            let sulid2 = generator.generate_with_token(11);
+
+            assert!(sulid1 < sulid2);
+            assert_eq!(sulid1.timestamp_ms(), 10);
+            assert_eq!(sulid2.timestamp_ms(), 11);
+            assert!(generator.owns(&sulid1) && generator.owns(&sulid2));
+            assert_eq!(
+                generator.generate_with_token(u64::MAX).timestamp_ms(),
+                bitmask!(48 => u64)
+            );
+        }
+
+        #[test]
+        /// Test that the generator is Send + Sync.
+        fn generator_is_send_sync() {
+            assert_send_sync::<SulidGenerator>();
+            assert_send_sync::<Arc<SulidGenerator>>();
+        }
+
+        #[test]
+        /// Test that a shared generator produces unique IDs across threads.
+        fn shared_generator_across_threads() {
+            use std::collections::HashSet;
+
+            let generator = SulidGenerator::v1_new(1, 2).into_shared();
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    let generator = Arc::clone(&generator);
+                    std::thread::spawn(move || {
+                        (0..1000)
+                            .map(|_| generator.generate_monotonic_blocking())
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            let mut ids = HashSet::new();
+            for handle in handles {
+                for sulid in handle.join().unwrap() {
+                    assert!(generator.owns(&sulid));
+                    assert!(ids.insert(sulid));
+                }
+            }
+            assert_eq!(ids.len(), 8000);
+        }
+
+        #[test]
+        /// Test that generate_with draws the random component from the given RNG.
+        fn generate_with_step_rng() {
+            use rand::rngs::mock::StepRng;
+
+            let generator = SulidGenerator::v2_new(9)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_234));
+            let mut rng = StepRng::new(1, 1);
+
+            assert_eq!(
+                generator.generate_with(&mut rng),
+                Sulid::v2_from_parts(1_234, (2 << 64) | 1, 9)
+            );
+            assert_eq!(
+                generator.generate_with(&mut rng),
+                Sulid::v2_from_parts(1_234, (4 << 64) | 3, 9)
+            );
+        }
+
+        #[cfg(feature = "rayon")]
+        #[test]
+        /// Test that a large parallel batch contains no duplicates.
+        fn generate_batch_parallel_unique() {
+            use std::collections::HashSet;
+
+            let generator = SulidGenerator::v1_new(4, 5);
+            let ids = generator.generate_batch_parallel(200_000);
+            assert_eq!(ids.len(), 200_000);
+            assert!(ids.iter().all(|id| generator.owns(id)));
+            assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+            assert!(generator.generate_batch_parallel(0).is_empty());
+        }
+
+        #[test]
+        /// Test that generators with different IDs have different identities.
+        fn worker_identity_distinguishes_generators() {
+            use crate::SulidVersion;
+
+            let v1 = SulidGenerator::v1_new(1, 2);
+            assert_eq!(
+                v1.worker_identity(),
+                WorkerIdentity::V1 {
+                    data_center_id: 1,
+                    machine_id: 2
+                }
+            );
+            assert_eq!(
+                v1.worker_identity(),
+                SulidGenerator::v1_new(1, 2).worker_identity()
+            );
+            assert_ne!(
+                v1.worker_identity(),
+                SulidGenerator::v1_new(2, 1).worker_identity()
+            );
+
+            let v2 = SulidGenerator::v2_new(34);
+            assert_eq!(v2.worker_identity(), WorkerIdentity::V2 { worker_id: 34 });
+            assert_ne!(
+                v2.worker_identity(),
+                SulidGenerator::v2_new(35).worker_identity()
+            );
+            // The same worker bits configured as V1 and V2 are still distinct identities.
+            assert_ne!(v1.worker_identity(), v2.worker_identity());
+            assert_eq!(v1.worker_identity().version(), SulidVersion::V1);
+            assert_eq!(v2.worker_identity().version(), SulidVersion::V2);
+        }
+
+        #[test]
+        /// Test that generate_after sorts after a reference from the future.
+        fn generate_after_future_reference() {
+            let generator = SulidGenerator::v1_new(0, 0)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_000));
+
+            let reference = Sulid::v1_from_parts(5_000, 41, 31, 31);
+            let sulid = generator.generate_after(&reference);
+            assert!(sulid > reference);
+            assert_eq!(sulid, Sulid::v1_from_parts(5_000, 42, 0, 0));
+
+            let exhausted = Sulid::v1_from_parts(5_000, bitmask!(Sulid::RAND_BITS => u128), 31, 31);
+            let sulid = generator.generate_after(&exhausted);
+            assert!(sulid > exhausted);
+            assert_eq!(sulid, Sulid::v1_from_parts(5_001, 0, 0, 0));
+
+            let past = Sulid::v1_from_parts(999, 0, 31, 31);
+            let sulid = generator.generate_after(&past);
+            assert!(sulid > past);
+            assert_eq!(sulid.timestamp_ms(), 1_000);
+            assert!(generator.owns(&sulid));
+        }
+
+        #[test]
+        #[should_panic(expected = "no SULID is greater than the reference")]
+        /// Test that generate_after panics after the largest SULID.
+        fn generate_after_max() {
+            SulidGenerator::v2_new(1).generate_after(&Sulid::MAX);
+        }
+
+        #[test]
+        /// Test that consecutive IDs cycle through the worker rotation in order.
+        fn worker_rotation_cycles() {
+            let generator = SulidGenerator::with_worker_rotation(vec![5, 1023, 0]);
+            let workers: Vec<_> = (0..7)
+                .map(|_| generator.generate().v2_worker_id())
+                .collect();
+            assert_eq!(workers, [5, 1023, 0, 5, 1023, 0, 5]);
+
+            assert!(generator.owns(&Sulid::v2_from_parts(1, 1, 1023)));
+            assert!(!generator.owns(&Sulid::v2_from_parts(1, 1, 6)));
+            assert_eq!(generator.generate_monotonic().unwrap().v2_worker_id(), 5);
+        }
+
+        #[test]
+        #[should_panic(expected = "worker_id must be in the range 0-1023")]
+        /// Test that a worker rotation with an out-of-range ID panics.
+        fn worker_rotation_out_of_range() {
+            let _ = SulidGenerator::with_worker_rotation(vec![1, 1024]);
+        }
+
+        #[test]
+        /// Test that a benchmark counter yields consecutive values and wraps around.
+        fn bench_counter_increments() {
+            let generator = SulidGenerator::bench_counter(0)
+                .with_clock(|| panic!("the clock must not be read"));
+            let ids: Vec<_> = (0..5).map(|_| generator.generate()).collect();
+            for w in ids.windows(2) {
+                assert_eq!(w[1].u128() - w[0].u128(), 1);
+            }
+            assert_eq!(ids[0], Sulid::nil());
+
+            let generator = SulidGenerator::bench_counter(u128::MAX);
+            assert_eq!(generator.generate(), Sulid::MAX);
+            assert_eq!(generator.generate(), Sulid::MIN);
+        }
+
+        #[test]
+        /// Test that generate_with_time returns the time the SULID was stamped with.
+        fn generate_with_time_matches_datetime() {
+            let stamp = SystemTime::UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+            let generator = SulidGenerator::v2_new(1).with_clock(move || stamp);
+            let (sulid, time) = generator.generate_with_time();
+            assert_eq!(time, stamp);
+            assert_eq!(sulid.timestamp_ms(), 1_700_000_000_123);
+            assert_eq!(
+                time.duration_since(sulid.datetime()).unwrap(),
+                Duration::from_nanos(456_789)
+            );
+
+            let generator = SulidGenerator::v2_new(1);
+            let (sulid, time) = generator.generate_with_time();
+            let elapsed = time.duration_since(sulid.datetime()).unwrap();
+            assert!(elapsed < Duration::from_millis(1));
+        }
+
+        #[cfg(feature = "checksum")]
+        #[test]
+        /// Test that checksummed SULIDs verify and detect a flipped bit.
+        fn generate_checksummed_detects_corruption() {
+            let generator = SulidGenerator::v1_new(3, 4);
+            for _ in 0..100 {
+                let sulid = generator.generate_checksummed();
+                assert!(sulid.verify_checksum());
+                assert_eq!(sulid.v1_data_center_id(), 3);
+                assert_eq!(sulid.v1_machine_id(), 4);
+
+                let corrupted = Sulid::from_u128(sulid.u128() ^ (1 << 64));
+                assert!(!corrupted.verify_checksum());
+            }
+        }
+
+        #[test]
+        /// Test that the probed clock resolution is plausible. The exact value varies by
+        /// platform: 1 ms on most, 10-16 ms on some older Windows versions.
+        fn clock_resolution_is_plausible() {
+            let resolution_ms = SulidGenerator::clock_resolution_ms();
+            assert!((1..=1000).contains(&resolution_ms));
+        }
+
+        #[test]
+        /// Test that threads sharing a thread-sharded generator embed distinct worker IDs.
+        fn thread_sharded_workers_differ() {
+            let generator = Arc::new(SulidGenerator::v2_new_thread_sharded(1000));
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    let generator = Arc::clone(&generator);
+                    std::thread::spawn(move || {
+                        let ids: Vec<_> = (0..10).map(|_| generator.generate()).collect();
+                        let worker_id = ids[0].v2_worker_id();
+                        assert!(ids.iter().all(|id| id.v2_worker_id() == worker_id));
+                        assert!(ids.iter().all(|id| generator.owns(id)));
+                        worker_id
+                    })
+                })
+                .collect();
+            let workers: std::collections::HashSet<_> = handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect();
+            assert_eq!(workers.len(), 4);
+            assert!(workers
+                .iter()
+                .all(|&worker_id| generator.owns(&Sulid::v2_from_parts(1, 1, worker_id))));
+            assert!(!generator.owns(&Sulid::v2_from_parts(1, 1, 999)));
+        }
+
+        #[test]
+        /// Test that a thread-sharded generator owns SULIDs stamped with `base_worker`.
+        fn thread_sharded_owns_base_worker() {
+            let generator = SulidGenerator::v2_new_thread_sharded(500);
+            assert!(generator.owns(&generator.generate_monotonic().unwrap()));
+            let (sulid, _) = generator.generate_with_time();
+            assert!(generator.owns(&sulid));
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic(expected = "repeated its previous value within one millisecond")]
+        /// Test that a repeated draw of the internal RNG trips the debug safeguard.
+        fn constant_rng_panics() {
+            let generator = SulidGenerator::v2_new(9)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_234));
+            {
+                let mut state = generator.state.lock().unwrap();
+                let next = state.rng.clone().gen::<u128>();
+                state.last_draw = Some((1_234, next));
+            }
+            let _ = generator.generate();
+        }
+
+        #[test]
+        /// Test that a constant caller-supplied RNG is accepted.
+        fn constant_caller_rng_allowed() {
+            use rand::rngs::mock::StepRng;
+
+            let generator = SulidGenerator::v2_new(9)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_234));
+            let mut rng = StepRng::new(7, 0);
+            assert_eq!(
+                generator.generate_with(&mut rng),
+                generator.generate_with(&mut rng)
+            );
+        }
+
+        #[test]
+        /// Test that a randomly picked worker ID comes from the allowed set.
+        fn random_worker_from_allowed_set() {
+            let allowed = [3, 500, 1023];
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut picked = std::collections::HashSet::new();
+            for _ in 0..64 {
+                let generator = SulidGenerator::v2_new_random_worker(&allowed, &mut rng);
+                let worker_id = generator.generate().v2_worker_id();
+                assert!(allowed.contains(&worker_id));
+                picked.insert(worker_id);
+            }
+            assert_eq!(picked.len(), allowed.len());
+        }
+
+        #[test]
+        /// Test that readable SULIDs have no character run longer than the threshold.
+        fn readable_runs_bounded() {
+            let longest_run = |sulid: Sulid| {
+                let text = sulid.to_string();
+                let chars = text.as_bytes();
+                let mut longest = 1;
+                let mut run = 1;
+                for pair in chars.windows(2) {
+                    run = if pair[0] == pair[1] { run + 1 } else { 1 };
+                    longest = longest.max(run);
+                }
+                longest
+            };
+            // "01D39ZY06F" and the worker characters "FZ" contain no runs themselves.
+            let generator = SulidGenerator::v2_new(511)
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(1_549_744_931_023));
+            for max_run in [1, 2, 3] {
+                for _ in 0..200 {
+                    assert!(longest_run(generator.generate_readable(max_run)) <= max_run);
+                }
+            }
+
+            // Runs within the worker characters can't be re-rolled away and are ignored.
+            let generator = SulidGenerator::v2_new(0);
+            let sulid = generator.generate_readable(1);
+            assert!(sulid.to_string().ends_with("00"));
+            assert_ne!(sulid.to_string().as_bytes()[23], b'0');
+        }
+
+        #[test]
+        /// Test that the per-millisecond count follows the injected clock.
+        fn current_ms_count_tracks_millisecond() {
+            let (clock, ms) = controllable_clock(1_700_000_000_000);
+            let generator = SulidGenerator::v2_new(1).with_clock(clock);
+            assert_eq!(generator.current_ms_count(), 0);
+
+            for _ in 0..5 {
+                generator.generate();
+            }
+            generator.generate_monotonic().unwrap();
+            generator.generate_monotonic().unwrap();
+            assert_eq!(generator.current_ms_count(), 7);
+
+            ms.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(generator.current_ms_count(), 0);
+            generator.generate();
+            assert_eq!(generator.current_ms_count(), 1);
+        }
+
+        #[test]
+        /// Test that try_generate reports contention and poisoning instead of blocking or panicking.
+        fn try_generate_reports_lock_errors() {
+            let generator = SulidGenerator::v2_new(1);
+            assert!(generator.try_generate().is_ok());
+
+            {
+                let _guard = generator.state.lock().unwrap();
+                assert_eq!(generator.try_generate(), Err(GenError::WouldBlock));
+            }
+
+            let poisoned = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _guard = generator.state.lock().unwrap();
+                panic!("poison the generator state");
+            }));
+            assert!(poisoned.is_err());
+            assert_eq!(generator.try_generate(), Err(GenError::Poisoned));
+        }
+
+        #[test]
+        /// Test that a dedup generator inserts every SULID and re-rolls known ones.
+        fn dedup_rerolls_known_candidates() {
+            let generator = SulidGenerator::v2_new(1).with_dedup(1 << 20);
+            let ids: std::collections::HashSet<_> =
+                (0..10_000).map(|_| generator.generate()).collect();
+            assert_eq!(ids.len(), 10_000);
+            let mut state = generator.state.lock().unwrap();
+            let filter = state.dedup.as_mut().unwrap();
+            assert!(ids.iter().all(|&id| !filter.insert(id)));
+            drop(state);
+
+            let generator = SulidGenerator::bench_counter(5).with_dedup(1 << 10);
+            generator
+                .state
+                .lock()
+                .unwrap()
+                .dedup
+                .as_mut()
+                .unwrap()
+                .insert(Sulid::from_u128(5));
+            assert_eq!(generator.generate(), Sulid::from_u128(6));
+        }
+
+        #[test]
+        /// Test that a second-granularity SULID reconstructs the wall-clock second.
+        fn seconds_unit_reconstructs_second() {
+            const START_MS: u64 = 1_700_000_000_999;
+            let generator = SulidGenerator::v1_builder(1, 1)
+                .time_unit(TimeUnit::Seconds)
+                .build()
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(START_MS));
+            let second = SystemTime::UNIX_EPOCH + Duration::from_secs(START_MS / 1_000);
+
+            let sulid = generator.generate();
+            assert_eq!(sulid.timestamp_ms(), START_MS / 1_000);
+            assert_eq!(sulid.datetime_with_unit(TimeUnit::Seconds), second);
+            let (sulid, _) = generator.generate_with_time();
+            assert_eq!(sulid.datetime_with_unit(TimeUnit::Seconds), second);
+
+            let generator = SulidGenerator::v2_builder(1)
+                .time_unit(TimeUnit::CentiMillis)
+                .build()
+                .with_clock(|| SystemTime::UNIX_EPOCH + Duration::from_millis(START_MS));
+            assert_eq!(
+                generator
+                    .generate()
+                    .datetime_with_unit(TimeUnit::CentiMillis),
+                SystemTime::UNIX_EPOCH + Duration::from_millis(START_MS - 9)
+            );
+        }
+
+        #[test]
+        /// Test that tokens don't count towards the current millisecond.
+        fn current_ms_count_ignores_tokens() {
+            let now = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+            let generator = SulidGenerator::v2_new(1).with_clock(move || now);
+            generator.generate();
+            generator.generate_with_token(42);
+            generator.generate();
+            assert_eq!(generator.current_ms_count(), 2);
+        }
+
+        #[test]
+        /// Test that a saturated dedup filter is reported instead of panicking.
+        fn try_generate_reports_saturation() {
+            let generator = SulidGenerator::bench_counter(0).with_dedup(64);
+            generator
+                .state
+                .lock()
+                .unwrap()
+                .dedup
+                .as_mut()
+                .unwrap()
+                .words
+                .fill(u64::MAX);
+            assert_eq!(generator.try_generate(), Err(GenError::Saturated));
+            assert_eq!(
+                GenError::Saturated.to_string(),
+                "the dedup bloom filter is saturated"
+            );
         }
     }
 }
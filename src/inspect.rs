@@ -0,0 +1,134 @@
+//! Human-readable diagnostics for Sulids.
+
+use crate::sulid::std_feature::now;
+use crate::{Sulid, SulidVersion};
+use std::time::SystemTime;
+
+/// All derived views of a Sulid at once, returned by [`Sulid::inspect`].
+///
+/// Since a Sulid doesn't record whether it was created as V1 or V2, both the V1
+/// (data center and machine ID) and the V2 (worker ID) interpretation are included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SulidInspection {
+    /// The embedded Unix timestamp in milliseconds.
+    pub timestamp_ms: u64,
+    /// The timestamp rendered as an RFC 3339 UTC datetime with millisecond precision.
+    pub rfc3339: String,
+    /// The 70-bit random section as 18 lowercase hex digits.
+    pub random_hex: String,
+    /// The data center ID under the V1 interpretation.
+    pub data_center_id: u8,
+    /// The machine ID under the V1 interpretation.
+    pub machine_id: u8,
+    /// The worker ID under the V2 interpretation.
+    pub worker_id: u16,
+    /// The version guessed by [`Sulid::likely_version`].
+    pub likely_version: SulidVersion,
+    /// Whether the timestamp lies after the current system time.
+    pub is_future: bool,
+}
+
+impl Sulid {
+    /// Decodes all derived views of this sulid at once, for debugging.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let inspection = Sulid::v1_from_parts(1_700_000_000_000, 0xABC, 3, 4).inspect();
+    /// assert_eq!(inspection.rfc3339, "2023-11-14T22:13:20.000Z");
+    /// assert_eq!(inspection.random_hex, "000000000000000abc");
+    /// assert_eq!((inspection.data_center_id, inspection.machine_id), (3, 4));
+    /// assert!(!inspection.is_future);
+    /// ```
+    pub fn inspect(&self) -> SulidInspection {
+        let now_ms = now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis());
+        SulidInspection {
+            timestamp_ms: self.timestamp_ms(),
+            rfc3339: rfc3339(self.timestamp_ms()),
+            random_hex: format!("{:018x}", self.random()),
+            data_center_id: self.v1_data_center_id(),
+            machine_id: self.v1_machine_id(),
+            worker_id: self.v2_worker_id(),
+            likely_version: self.likely_version(),
+            is_future: u128::from(self.timestamp_ms()) > now_ms,
+        }
+    }
+}
+
+/// Renders a Unix timestamp in milliseconds as an RFC 3339 UTC datetime.
+fn rfc3339(timestamp_ms: u64) -> String {
+    let days = timestamp_ms / 86_400_000;
+    let ms_of_day = timestamp_ms % 86_400_000;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    )
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian `(year, month, day)`.
+///
+/// See Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sulid::bitmask;
+
+    #[test]
+    fn inspects_known_sulid() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_123, 0x2A_DEAD_BEEF_CAFE_BABE, 17, 29);
+        assert_eq!(
+            sulid.inspect(),
+            SulidInspection {
+                timestamp_ms: 1_700_000_000_123,
+                rfc3339: "2023-11-14T22:13:20.123Z".to_owned(),
+                random_hex: "2adeadbeefcafebabe".to_owned(),
+                data_center_id: 17,
+                machine_id: 29,
+                worker_id: (17 << 5) | 29,
+                likely_version: SulidVersion::V2,
+                is_future: false,
+            }
+        );
+    }
+
+    #[test]
+    fn renders_rfc3339() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(rfc3339(1_709_251_199_999), "2024-02-29T23:59:59.999Z");
+        assert_eq!(
+            rfc3339(bitmask!(Sulid::TIME_BITS => u64)),
+            "10889-08-02T05:31:50.655Z"
+        );
+    }
+
+    #[test]
+    fn detects_future_timestamp() {
+        let future = Sulid::v2_from_parts(bitmask!(Sulid::TIME_BITS => u64), 0, 1);
+        assert!(future.inspect().is_future);
+        assert!(!Sulid::v2_new(1).inspect().is_future);
+    }
+}
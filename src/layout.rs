@@ -0,0 +1,366 @@
+//! Configurable Sulid bit layouts.
+//!
+//! [`Sulid`] uses a fixed layout with a 5-bit data center ID and a 5-bit machine ID.
+//! Deployments that need more workers can use [`CustomSulid`] with a [`Layout`] that
+//! widens the worker fields at the expense of the random field.
+//!
+//! ```text
+//! | 48-bit Timestamp | (80 - DC - MC)-bit Random Number | DC-bit Data Center ID | MC-bit Machine ID |
+//! ```
+//!
+//! Code that should work with both Sulids and plain ULIDs can be written against the
+//! [`IdLayout`] trait.
+
+use crate::sulid::bitmask;
+use crate::{Sulid, ULID_LEN};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use ulid::Ulid;
+
+/// The operations shared by time-ordered 128-bit identifiers such as [`Sulid`] and
+/// [`Ulid`], so storage code can be written once for either type.
+///
+/// # Example
+/// ```rust
+/// use sulid::ulid::Ulid;
+/// use sulid::{IdLayout, Sulid};
+///
+/// fn key<T: IdLayout>(id: &T) -> String {
+///     let mut buffer = [0; sulid::ULID_LEN];
+///     format!("{}/{}", id.timestamp_ms() / 86_400_000, id.encode(&mut buffer))
+/// }
+///
+/// assert_eq!(key(&Sulid::example()), "17936/01D39ZY06FGSCTVN4T2V9PKHFZ");
+/// assert_eq!(key(&Ulid(Sulid::example().u128())), "17936/01D39ZY06FGSCTVN4T2V9PKHFZ");
+/// ```
+pub trait IdLayout: Sized {
+    /// The number of bits in the random portion
+    const RAND_BITS: u8;
+
+    /// Gets the timestamp section in milliseconds since the Unix epoch
+    fn timestamp_ms(&self) -> u64;
+
+    /// Gets the random section
+    fn random(&self) -> u128;
+
+    /// Creates an identifier from a timestamp and a random number.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    fn from_parts(timestamp_ms: u64, random: u128) -> Self;
+
+    /// Writes the Crockford Base32 encoded string of the identifier to `buf`
+    fn encode<'buf>(&self, buf: &'buf mut [u8; ULID_LEN]) -> &'buf mut str;
+}
+
+/// A Sulid's layout has a 70-bit random section followed by the worker ID.
+///
+/// [`IdLayout::from_parts`] creates a V2 Sulid with the worker ID 0.
+impl IdLayout for Sulid {
+    const RAND_BITS: u8 = Sulid::RAND_BITS;
+
+    fn timestamp_ms(&self) -> u64 {
+        Sulid::timestamp_ms(self)
+    }
+
+    fn random(&self) -> u128 {
+        Sulid::random(self)
+    }
+
+    fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        Sulid::v2_from_parts(timestamp_ms, random, 0)
+    }
+
+    fn encode<'buf>(&self, buf: &'buf mut [u8; ULID_LEN]) -> &'buf mut str {
+        self.array_to_str(buf)
+    }
+}
+
+/// A ULID's layout has an 80-bit random section.
+impl IdLayout for Ulid {
+    const RAND_BITS: u8 = Ulid::RAND_BITS;
+
+    fn timestamp_ms(&self) -> u64 {
+        Ulid::timestamp_ms(self)
+    }
+
+    fn random(&self) -> u128 {
+        Ulid::random(self)
+    }
+
+    fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        Ulid::from_parts(timestamp_ms, random)
+    }
+
+    fn encode<'buf>(&self, buf: &'buf mut [u8; ULID_LEN]) -> &'buf mut str {
+        self.array_to_str(buf)
+    }
+}
+
+/// Describes the widths of the worker fields of a [`CustomSulid`].
+///
+/// The timestamp always occupies the high 48 bits; the random field takes the bits
+/// left over by the worker fields.
+pub trait Layout {
+    /// The number of bits for data center ID
+    const DATA_CENTER_BITS: u8;
+    /// The number of bits for machine ID
+    const MACHINE_BITS: u8;
+    /// The number of bits for worker ID, which is a combination of data_center_id and machine_id.
+    const WORKER_BITS: u8 = Self::DATA_CENTER_BITS + Self::MACHINE_BITS;
+    /// The number of bits in the random portion
+    const RAND_BITS: u8 = 128 - Sulid::TIME_BITS - Self::WORKER_BITS;
+}
+
+/// A [`Layout`] with a `DC`-bit data center ID and a `MC`-bit machine ID.
+///
+/// Both `DC` and `MC` must not exceed 16 bits, which is checked at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BitLayout<const DC: u8, const MC: u8>;
+
+impl<const DC: u8, const MC: u8> Layout for BitLayout<DC, MC> {
+    const DATA_CENTER_BITS: u8 = DC;
+    const MACHINE_BITS: u8 = MC;
+}
+
+/// The layout used by [`Sulid`]: a 5-bit data center ID and a 5-bit machine ID.
+pub type DefaultLayout = BitLayout<5, 5>;
+
+/// A Sulid whose field widths are determined by the layout `L`.
+///
+/// With the [`DefaultLayout`] it has exactly the same bits as a [`Sulid`], and the two
+/// convert into each other losslessly. Like a Sulid, it sorts by timestamp first.
+///
+/// # Example
+/// ```rust
+/// use sulid::{BitLayout, CustomSulid};
+///
+/// type Sulid8x8 = CustomSulid<BitLayout<8, 8>>;
+///
+/// let sulid = Sulid8x8::from_parts(1_700_000_000_000, 42, 200, 100);
+/// assert_eq!(sulid.timestamp_ms(), 1_700_000_000_000);
+/// assert_eq!(sulid.random(), 42);
+/// assert_eq!(sulid.data_center_id(), 200);
+/// assert_eq!(sulid.machine_id(), 100);
+/// ```
+pub struct CustomSulid<L: Layout = DefaultLayout>(u128, PhantomData<L>);
+
+impl<L: Layout> CustomSulid<L> {
+    /// Fails to compile if a worker field is wider than 16 bits.
+    const VALID_LAYOUT: () = assert!(
+        L::DATA_CENTER_BITS <= 16 && L::MACHINE_BITS <= 16,
+        "data center and machine IDs must not exceed 16 bits"
+    );
+
+    /// Create a CustomSulid from integer representation.
+    pub const fn from_u128(u: u128) -> Self {
+        Self(u, PhantomData)
+    }
+
+    /// Gets the integer representation
+    pub const fn u128(&self) -> u128 {
+        self.0
+    }
+
+    /// Create a CustomSulid from separated parts.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    pub const fn from_parts(
+        timestamp_ms: u64,
+        random: u128,
+        data_center_id: u16,
+        machine_id: u16,
+    ) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_LAYOUT;
+
+        let time_part = (timestamp_ms & bitmask!(Sulid::TIME_BITS => u64)) as u128;
+        let rand_part = random & bitmask!(L::RAND_BITS => u128);
+        let data_center_part = (data_center_id & bitmask!(L::DATA_CENTER_BITS => u16)) as u128;
+        let machine_part = (machine_id & bitmask!(L::MACHINE_BITS => u16)) as u128;
+
+        Self::from_u128(
+            (time_part << (L::RAND_BITS + L::WORKER_BITS))
+                | (rand_part << L::WORKER_BITS)
+                | (data_center_part << L::MACHINE_BITS)
+                | machine_part,
+        )
+    }
+
+    /// Gets the timestamp section of this sulid
+    pub const fn timestamp_ms(&self) -> u64 {
+        (self.0 >> (L::RAND_BITS + L::WORKER_BITS)) as u64
+    }
+
+    /// Gets the random section of this sulid
+    pub const fn random(&self) -> u128 {
+        (self.0 >> L::WORKER_BITS) & bitmask!(L::RAND_BITS => u128)
+    }
+
+    /// Gets the data center ID portion of this sulid
+    pub const fn data_center_id(&self) -> u16 {
+        ((self.0 >> L::MACHINE_BITS) & bitmask!(L::DATA_CENTER_BITS => u128)) as u16
+    }
+
+    /// Gets the machine ID portion of this sulid
+    pub const fn machine_id(&self) -> u16 {
+        (self.0 & bitmask!(L::MACHINE_BITS => u128)) as u16
+    }
+
+    /// Gets the worker ID portion of this sulid, i.e. the data center ID and machine ID combined.
+    pub const fn worker_id(&self) -> u32 {
+        (self.0 & bitmask!(L::WORKER_BITS => u128)) as u32
+    }
+}
+
+impl From<Sulid> for CustomSulid<DefaultLayout> {
+    fn from(sulid: Sulid) -> Self {
+        Self::from_u128(sulid.u128())
+    }
+}
+
+impl From<CustomSulid<DefaultLayout>> for Sulid {
+    fn from(sulid: CustomSulid<DefaultLayout>) -> Self {
+        Sulid::from_u128(sulid.u128())
+    }
+}
+
+impl<L: Layout> Clone for CustomSulid<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: Layout> Copy for CustomSulid<L> {}
+
+impl<L: Layout> PartialEq for CustomSulid<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<L: Layout> Eq for CustomSulid<L> {}
+
+impl<L: Layout> PartialOrd for CustomSulid<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L: Layout> Ord for CustomSulid<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<L: Layout> Hash for CustomSulid<L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<L: Layout> Default for CustomSulid<L> {
+    fn default() -> Self {
+        Self::from_u128(0)
+    }
+}
+
+impl<L: Layout> fmt::Debug for CustomSulid<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomSulid").field(&self.0).finish()
+    }
+}
+
+impl<L: Layout> fmt::Display for CustomSulid<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let mut buffer = [0; ULID_LEN];
+        write!(f, "{}", Ulid(self.0).array_to_str(&mut buffer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sulid8x8 = CustomSulid<BitLayout<8, 8>>;
+
+    /// Creates the identifier that follows `id` in the same millisecond.
+    fn successor<T: IdLayout>(id: &T) -> T {
+        T::from_parts(id.timestamp_ms(), id.random() + 1)
+    }
+
+    #[test]
+    fn generic_over_id_layout() {
+        let sulid = successor(&Sulid::v2_from_parts(1_700_000_000_000, 41, 0));
+        assert_eq!(sulid, Sulid::v2_from_parts(1_700_000_000_000, 42, 0));
+
+        let ulid = successor(&Ulid::from_parts(1_700_000_000_000, 41));
+        assert_eq!(ulid, Ulid::from_parts(1_700_000_000_000, 42));
+
+        assert_eq!(<Sulid as IdLayout>::RAND_BITS, 70);
+        assert_eq!(<Ulid as IdLayout>::RAND_BITS, 80);
+
+        let mut buffer = [0; ULID_LEN];
+        assert_eq!(
+            IdLayout::encode(&Sulid::example(), &mut buffer),
+            "01D39ZY06FGSCTVN4T2V9PKHFZ"
+        );
+        let ulid = Ulid(Sulid::example().u128());
+        assert_eq!(
+            IdLayout::encode(&ulid, &mut buffer),
+            "01D39ZY06FGSCTVN4T2V9PKHFZ"
+        );
+        assert_eq!(
+            IdLayout::timestamp_ms(&ulid),
+            Sulid::example().timestamp_ms()
+        );
+    }
+
+    #[test]
+    fn default_layout_matches_sulid() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, 123456789, 17, 29);
+        let custom = CustomSulid::<DefaultLayout>::from_parts(1_700_000_000_000, 123456789, 17, 29);
+
+        assert_eq!(CustomSulid::from(sulid), custom);
+        assert_eq!(Sulid::from(custom), sulid);
+        assert_eq!(custom.worker_id(), u32::from(sulid.v2_worker_id()));
+        #[cfg(feature = "std")]
+        assert_eq!(custom.to_string(), sulid.to_string());
+    }
+
+    #[test]
+    fn custom_layout_packing() {
+        assert_eq!(<BitLayout<8, 8> as Layout>::WORKER_BITS, 16);
+        assert_eq!(<BitLayout<8, 8> as Layout>::RAND_BITS, 64);
+
+        let sulid = Sulid8x8::from_parts(0xABCD, u64::MAX as u128, 0xFE, 0xDC);
+        assert_eq!(sulid.timestamp_ms(), 0xABCD);
+        assert_eq!(sulid.random(), u64::MAX as u128);
+        assert_eq!(sulid.data_center_id(), 0xFE);
+        assert_eq!(sulid.machine_id(), 0xDC);
+        assert_eq!(sulid.worker_id(), 0xFEDC);
+        assert_eq!(
+            sulid.u128(),
+            (0xABCD << 80) | ((u64::MAX as u128) << 16) | 0xFEDC
+        );
+
+        // Overflow bits are discarded.
+        let sulid = Sulid8x8::from_parts(1, 1 << 64, 0x1FF, 0x100);
+        assert_eq!(sulid.random(), 0);
+        assert_eq!(sulid.data_center_id(), 0xFF);
+        assert_eq!(sulid.machine_id(), 0);
+    }
+
+    #[test]
+    fn custom_layout_ordering() {
+        let earlier = Sulid8x8::from_parts(1, u64::MAX as u128, 0xFF, 0xFF);
+        let later = Sulid8x8::from_parts(2, 0, 0, 0);
+        assert!(earlier < later);
+
+        let low_random = Sulid8x8::from_parts(2, 1, 0xFF, 0xFF);
+        let high_random = Sulid8x8::from_parts(2, 2, 0, 0);
+        assert!(later < low_random);
+        assert!(low_random < high_random);
+    }
+}
@@ -35,7 +35,7 @@
 //!
 //! Here is a visual breakdown of the SULID format:
 //!
-//! ```
+//! ```text
 //! | 48-bit Timestamp | 70-bit Random Number | 5-bit Data Center ID | 5-bit Machine ID |
 //! ```
 //!
@@ -46,7 +46,7 @@
 //!
 //! Here is a visual breakdown of the SULID format:
 //!
-//! ```
+//! ```text
 //! | 48-bit Timestamp | 70-bit Random Number | 10-bit Worker ID |
 //! ```
 //!
@@ -87,11 +87,52 @@
 //! }
 //! ```
 
-pub use generator::SulidGenerator;
-pub use sulid::Sulid;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use ffi::CSulid;
+#[cfg(feature = "std")]
+pub use generator::{GenContext, GenError, SulidGeneratorBuilder};
+pub use generator::{SulidGenerator, WorkerIdentity};
+#[cfg(feature = "std")]
+pub use inspect::SulidInspection;
+pub use layout::{BitLayout, CustomSulid, DefaultLayout, IdLayout, Layout};
+#[cfg(feature = "std")]
+pub use monotonic::MonotonicSulidGenerator;
+#[cfg(feature = "std")]
+pub use pool::SulidGeneratorPool;
+#[cfg(feature = "std")]
+pub use set::SulidSet;
+#[cfg(feature = "std")]
+pub use slice::entropy_bits_estimate;
+pub use slice::{
+    all_same_worker, first_non_monotonic, is_monotonic, is_sorted, sort_unstable, DisplaySlice,
+};
+#[cfg(feature = "alloc")]
+pub use slice::{find_duplicates, group_by_worker, inter_arrival_ms, merge_sorted};
+#[cfg(feature = "std")]
+pub use sulid::std_feature::timestamp_headroom_years;
+pub use sulid::{
+    combine_worker_id, range, split_worker_id, IdRangeError, ParseError, StrictDecodeError, Sulid,
+    SulidDecodeError, SulidDiff, SulidVersion, TimeKey, TimeUnit,
+};
+pub use vectors::{test_vectors, TestVector};
 // Republic ULID
 pub use ulid;
 pub use ulid::{DecodeError, EncodeError, ULID_LEN};
 
+mod ffi;
 mod generator;
+#[cfg(feature = "std")]
+mod inspect;
+mod layout;
+#[cfg(feature = "std")]
+mod monotonic;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "std")]
+mod set;
+mod slice;
 pub(crate) mod sulid;
+mod tenant;
+mod vectors;
@@ -0,0 +1,142 @@
+//! A generator with a strict monotonicity guarantee.
+
+use crate::{Sulid, SulidGenerator};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// A generator whose SULIDs are strictly increasing.
+///
+/// Unlike [`SulidGenerator`], which draws a fresh random component for every SULID, this
+/// generator remembers the last SULID it emitted and never returns one that is less
+/// than or equal to it:
+///
+/// * When the clock has advanced, a fresh SULID with a new random component is emitted.
+/// * Within the same millisecond, or when the clock stepped backwards, the timestamp of
+///   the last SULID is reused and its random component is incremented by one.
+/// * When the random component is exhausted, the last timestamp is advanced by one
+///   millisecond and the random component starts over at zero, instead of failing.
+///
+/// # Example
+/// ```rust
+/// use sulid::MonotonicSulidGenerator;
+///
+/// let generator = MonotonicSulidGenerator::v2_new(1);
+/// let first = generator.generate();
+/// let second = generator.generate();
+/// assert!(first < second);
+/// ```
+pub struct MonotonicSulidGenerator {
+    /// The generator drawing fresh SULIDs when the clock advances.
+    generator: SulidGenerator,
+    /// The last SULID emitted.
+    last: Mutex<Sulid>,
+}
+
+impl MonotonicSulidGenerator {
+    /// Creates a new V1 MonotonicSulidGenerator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data_center_id` or `machine_id` is outside the 0-31 range.
+    pub fn v1_new(data_center_id: u8, machine_id: u8) -> Self {
+        Self::from_generator(SulidGenerator::v1_new(data_center_id, machine_id))
+    }
+
+    /// Creates a new V2 MonotonicSulidGenerator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `worker_id` is outside the 0-1023 range.
+    pub fn v2_new(worker_id: u16) -> Self {
+        Self::from_generator(SulidGenerator::v2_new(worker_id))
+    }
+
+    /// Replaces the clock used to timestamp generated SULIDs.
+    ///
+    /// See [`SulidGenerator::with_clock`].
+    pub fn with_clock<F>(self, clock: F) -> Self
+    where
+        F: Fn() -> SystemTime + Send + Sync + 'static,
+    {
+        Self::from_generator(self.generator.with_clock(clock))
+    }
+
+    /// Generates a new SULID that is strictly greater than the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the previous SULID already has the largest timestamp and random component.
+    pub fn generate(&self) -> Sulid {
+        let mut last = self.last.lock().unwrap();
+        *last = self.generator.generate_after(&last);
+        *last
+    }
+
+    /// Returns the last SULID generated, or nil if none was generated yet.
+    pub fn last(&self) -> Sulid {
+        *self.last.lock().unwrap()
+    }
+
+    fn from_generator(generator: SulidGenerator) -> Self {
+        MonotonicSulidGenerator {
+            generator,
+            last: Mutex::new(Sulid::nil()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::controllable_clock;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    /// Returns a generator whose clock reads the milliseconds stored in the returned atomic.
+    fn controlled(start_ms: u64) -> (MonotonicSulidGenerator, Arc<AtomicU64>) {
+        let (clock, ms) = controllable_clock(start_ms);
+        (MonotonicSulidGenerator::v1_new(1, 2).with_clock(clock), ms)
+    }
+
+    #[test]
+    fn strictly_increasing_across_milliseconds() {
+        let (generator, ms) = controlled(1_700_000_000_000);
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            ids.extend((0..100).map(|_| generator.generate()));
+            ms.fetch_add(1, Ordering::SeqCst);
+        }
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ids[99].timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(ids[100].timestamp_ms(), 1_700_000_000_001);
+        assert_eq!(ids[99].random(), ids[0].random() + 99);
+        assert!(ids
+            .iter()
+            .all(|id| (id.v1_data_center_id(), id.v1_machine_id()) == (1, 2)));
+        assert_eq!(generator.last(), ids[299]);
+    }
+
+    #[test]
+    fn reuses_timestamp_after_clock_backstep() {
+        let (generator, ms) = controlled(1_700_000_000_000);
+        let before = generator.generate();
+        ms.store(1_699_999_999_000, Ordering::SeqCst);
+        let after: Vec<_> = (0..10).map(|_| generator.generate()).collect();
+
+        assert!(before < after[0]);
+        assert!(after.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(after
+            .iter()
+            .all(|id| id.timestamp_ms() == before.timestamp_ms()));
+    }
+
+    #[test]
+    fn carries_into_next_millisecond() {
+        let (generator, _) = controlled(1_700_000_000_000);
+        let max_random = Sulid::v1_from_parts(1_700_000_000_000, u128::MAX >> 58, 1, 2);
+        *generator.last.lock().unwrap() = max_random;
+        let next = generator.generate();
+        assert_eq!(next.timestamp_ms(), 1_700_000_000_001);
+        assert_eq!(next.random(), 0);
+    }
+}
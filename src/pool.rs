@@ -0,0 +1,99 @@
+//! A pool of generators spread over a contiguous block of worker IDs.
+
+use crate::{Sulid, SulidGenerator};
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A pool of [`SulidGenerator`]s, one per worker ID of a contiguous range.
+///
+/// [`SulidGeneratorPool::generate`] hands out the generators in round-robin order, so
+/// consecutive calls embed consecutive worker IDs. Each worker ID has its own 70-bit
+/// random space per millisecond, so the number of SULIDs the pool can produce within a
+/// millisecond at a given collision probability grows linearly with the number of
+/// workers, and concurrent callers mostly lock different generators.
+///
+/// SULIDs from different workers are only ordered by their timestamps; within a
+/// millisecond their order is random.
+///
+/// # Example
+/// ```rust
+/// use sulid::SulidGeneratorPool;
+///
+/// let pool = SulidGeneratorPool::new(100..132);
+/// assert_eq!(pool.len(), 32);
+/// assert_eq!(pool.generate().v2_worker_id(), 100);
+/// assert_eq!(pool.generate().v2_worker_id(), 101);
+/// ```
+pub struct SulidGeneratorPool {
+    /// The generators, in ascending order of their worker IDs.
+    generators: Vec<SulidGenerator>,
+    /// The number of SULIDs generated so far, which selects the next generator.
+    next: AtomicUsize,
+}
+
+impl SulidGeneratorPool {
+    /// Creates a pool with one V2 generator for each worker ID in `worker_range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `worker_range` is empty or extends beyond the 0-1023 range.
+    pub fn new(worker_range: Range<u16>) -> Self {
+        assert!(!worker_range.is_empty(), "worker_range must not be empty");
+        assert!(
+            worker_range.end <= 1024,
+            "worker_range must be within the range 0-1023"
+        );
+        SulidGeneratorPool {
+            generators: worker_range.map(SulidGenerator::v2_new).collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of generators, i.e. worker IDs, in the pool.
+    pub fn len(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// Always returns `false`, since a pool has at least one generator.
+    pub fn is_empty(&self) -> bool {
+        self.generators.is_empty()
+    }
+
+    /// Generates a new SULID with the next generator in round-robin order.
+    pub fn generate(&self) -> Sulid {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.generators.len();
+        self.generators[index].generate()
+    }
+
+    /// Checks whether the given SULID carries one of the pool's worker IDs.
+    pub fn owns(&self, sulid: &Sulid) -> bool {
+        self.generators
+            .iter()
+            .any(|generator| generator.owns(sulid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn burst_spans_workers_without_collisions() {
+        let pool = SulidGeneratorPool::new(100..132);
+        let ids: Vec<_> = (0..10_000).map(|_| pool.generate()).collect();
+
+        let unique: HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+        let workers: HashSet<_> = ids.iter().map(Sulid::v2_worker_id).collect();
+        assert_eq!(workers, (100..132).collect());
+        assert!(ids.iter().all(|id| pool.owns(id)));
+        assert!(!pool.owns(&Sulid::v2_from_parts(0, 0, 132)));
+    }
+
+    #[test]
+    #[should_panic(expected = "worker_range must be within the range 0-1023")]
+    fn out_of_range_workers() {
+        let _ = SulidGeneratorPool::new(1000..1025);
+    }
+}
@@ -0,0 +1,150 @@
+//! A sorted, deduplicated collection of Sulids.
+
+use crate::{sulid::bitmask, Sulid};
+use std::collections::btree_set::{Iter, Range};
+use std::collections::BTreeSet;
+use std::ops::Bound;
+
+/// A sorted set of Sulids backed by a [`BTreeSet`].
+///
+/// Since Sulids sort by timestamp first, the set supports efficient queries by time window.
+///
+/// # Example
+/// ```rust
+/// use sulid::{Sulid, SulidSet};
+///
+/// let set: SulidSet = [
+///     Sulid::v2_from_parts(30, 0, 1),
+///     Sulid::v2_from_parts(10, 0, 1),
+///     Sulid::v2_from_parts(20, 0, 1),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let window: Vec<_> = set.range_by_time(10, 30).map(|s| s.timestamp_ms()).collect();
+/// assert_eq!(window, [10, 20]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SulidSet(BTreeSet<Sulid>);
+
+impl SulidSet {
+    /// Creates an empty SulidSet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a Sulid to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, sulid: Sulid) -> bool {
+        self.0.insert(sulid)
+    }
+
+    /// Returns `true` if the set contains the given Sulid.
+    pub fn contains(&self, sulid: &Sulid) -> bool {
+        self.0.contains(sulid)
+    }
+
+    /// Returns the number of Sulids in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set contains no Sulids.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets an iterator that visits the Sulids in ascending order.
+    pub fn iter(&self) -> Iter<'_, Sulid> {
+        self.0.iter()
+    }
+
+    /// Gets an iterator over the Sulids whose timestamp is in `start_ms..end_ms`, in ascending order.
+    ///
+    /// An empty iterator is returned if `start_ms >= end_ms`.
+    pub fn range_by_time(&self, start_ms: u64, end_ms: u64) -> Range<'_, Sulid> {
+        let end_ms = end_ms.max(start_ms);
+        let start = match time_floor(start_ms) {
+            Some(start) => Bound::Included(start),
+            None => Bound::Excluded(Sulid::from_u128(u128::MAX)),
+        };
+        let end = match time_floor(end_ms) {
+            Some(end) => Bound::Excluded(end),
+            None => Bound::Unbounded,
+        };
+        self.0.range((start, end))
+    }
+}
+
+/// Returns the smallest Sulid with the given timestamp, or `None` if it is not representable.
+fn time_floor(timestamp_ms: u64) -> Option<Sulid> {
+    if timestamp_ms > bitmask!(Sulid::TIME_BITS => u64) {
+        None
+    } else {
+        Some(Sulid::v2_from_parts(timestamp_ms, 0, 0))
+    }
+}
+
+impl FromIterator<Sulid> for SulidSet {
+    fn from_iter<I: IntoIterator<Item = Sulid>>(iter: I) -> Self {
+        Self(BTreeSet::from_iter(iter))
+    }
+}
+
+impl Extend<Sulid> for SulidSet {
+    fn extend<I: IntoIterator<Item = Sulid>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<'a> IntoIterator for &'a SulidSet {
+    type Item = &'a Sulid;
+    type IntoIter = Iter<'a, Sulid>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_out_of_order_ids() {
+        let mut set: SulidSet = [
+            Sulid::v1_from_parts(3, 7, 1, 1),
+            Sulid::v1_from_parts(1, 7, 1, 1),
+            Sulid::v1_from_parts(2, 7, 1, 1),
+        ]
+        .into_iter()
+        .collect();
+        set.extend([
+            Sulid::v1_from_parts(1, 7, 1, 1),
+            Sulid::v1_from_parts(0, 7, 1, 1),
+        ]);
+
+        assert_eq!(set.len(), 4);
+        let timestamps: Vec<_> = set.iter().map(Sulid::timestamp_ms).collect();
+        assert_eq!(timestamps, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn queries_time_window() {
+        let set: SulidSet = (0..10)
+            .rev()
+            .flat_map(|ts| {
+                [
+                    Sulid::v2_from_parts(ts, 0, 1),
+                    Sulid::v2_from_parts(ts, bitmask!(Sulid::RAND_BITS => u128), 1023),
+                ]
+            })
+            .collect();
+
+        let window: Vec<_> = set.range_by_time(3, 6).map(Sulid::timestamp_ms).collect();
+        assert_eq!(window, [3, 3, 4, 4, 5, 5]);
+        assert_eq!(set.range_by_time(8, u64::MAX).count(), 4);
+        assert_eq!(set.range_by_time(6, 3).count(), 0);
+        assert_eq!(set.range_by_time(9, 9).count(), 0);
+        assert_eq!(set.range_by_time(u64::MAX, u64::MAX).count(), 0);
+    }
+}
@@ -0,0 +1,485 @@
+//! Helpers operating on slices of Sulids.
+
+use crate::Sulid;
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BinaryHeap};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Reverse;
+use core::fmt;
+
+/// Checks whether the given Sulids are strictly increasing.
+///
+/// An empty or single-element slice is considered monotonic.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [Sulid::from_u128(1), Sulid::from_u128(2), Sulid::from_u128(3)];
+/// assert!(sulid::is_monotonic(&ids));
+/// ```
+pub fn is_monotonic(ids: &[Sulid]) -> bool {
+    first_non_monotonic(ids).is_none()
+}
+
+/// Returns the index of the first Sulid that is not strictly greater than its predecessor.
+///
+/// Returns `None` if the slice is strictly increasing.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [Sulid::from_u128(1), Sulid::from_u128(3), Sulid::from_u128(2)];
+/// assert_eq!(sulid::first_non_monotonic(&ids), Some(2));
+/// ```
+pub fn first_non_monotonic(ids: &[Sulid]) -> Option<usize> {
+    ids.windows(2).position(|w| w[0] >= w[1]).map(|i| i + 1)
+}
+
+/// Sorts the given Sulids in ascending order by their integer representation.
+///
+/// This is the canonical way to sort Sulids: it is equivalent to sorting by [`Ord`],
+/// but compares the raw `u128` values directly and does not preserve the order of
+/// equal elements.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let mut ids = [Sulid::from_u128(3), Sulid::from_u128(1), Sulid::from_u128(2)];
+/// sulid::sort_unstable(&mut ids);
+/// assert!(sulid::is_sorted(&ids));
+/// ```
+pub fn sort_unstable(ids: &mut [Sulid]) {
+    ids.sort_unstable_by_key(Sulid::u128)
+}
+
+/// Checks whether the given Sulids are sorted in ascending order.
+///
+/// Unlike [`is_monotonic`], equal adjacent Sulids are allowed.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [Sulid::from_u128(1), Sulid::from_u128(1), Sulid::from_u128(2)];
+/// assert!(sulid::is_sorted(&ids));
+/// assert!(!sulid::is_monotonic(&ids));
+/// ```
+pub fn is_sorted(ids: &[Sulid]) -> bool {
+    ids.windows(2).all(|w| w[0] <= w[1])
+}
+
+/// Returns the Sulids that occur more than once in a sorted slice.
+///
+/// Each duplicated Sulid is reported once, in ascending order, no matter how often it
+/// repeats. The scan only compares adjacent elements, so it runs in O(n).
+///
+/// The slice must be sorted, e.g. with [`sort_unstable`]. For unsorted input the result
+/// is unspecified: duplicates that are not adjacent are missed.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [Sulid::from_u128(1), Sulid::from_u128(2), Sulid::from_u128(2)];
+/// assert_eq!(sulid::find_duplicates(&ids), [Sulid::from_u128(2)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn find_duplicates(sorted_ids: &[Sulid]) -> Vec<Sulid> {
+    let mut duplicates = Vec::new();
+    for w in sorted_ids.windows(2) {
+        if w[0] == w[1] && duplicates.last() != Some(&w[1]) {
+            duplicates.push(w[1]);
+        }
+    }
+    duplicates
+}
+
+/// Returns the millisecond gaps between the timestamps of consecutive Sulids.
+///
+/// The result has one element less than the input, or none for an empty slice. Sulids
+/// from the same millisecond yield a gap of zero, so bursts show up as runs of zeros.
+///
+/// The slice must be sorted, e.g. with [`sort_unstable`]. For unsorted input, a step
+/// back in time is reported as a gap of zero.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [
+///     Sulid::v2_from_parts(100, 0, 1),
+///     Sulid::v2_from_parts(100, 1, 1),
+///     Sulid::v2_from_parts(250, 0, 1),
+/// ];
+/// assert_eq!(sulid::inter_arrival_ms(&ids), [0, 150]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn inter_arrival_ms(sorted_ids: &[Sulid]) -> Vec<u64> {
+    sorted_ids
+        .windows(2)
+        .map(|w| w[1].timestamp_ms().saturating_sub(w[0].timestamp_ms()))
+        .collect()
+}
+
+/// Estimates the entropy of the random sections of the given Sulids, in bits.
+///
+/// For each of the 70 random bit positions, the fraction `p` of Sulids with that bit set
+/// is measured, and the binary Shannon entropy `-p·log2(p) - (1-p)·log2(1-p)` of the
+/// position is computed. The result is the sum over all positions, ranging from 0 (every
+/// bit constant) to 70 (every bit set in exactly half of the Sulids).
+///
+/// A healthy RNG yields close to 70 bits for a batch of a few hundred Sulids, while a
+/// broken or misconfigured deterministic RNG shows up as a much lower estimate. Since
+/// positions are measured independently, correlations between bits are not detected,
+/// so a high estimate doesn't prove the RNG is sound. An empty slice yields 0.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let constant = [Sulid::v2_from_parts(1, 42, 1); 100];
+/// assert_eq!(sulid::entropy_bits_estimate(&constant), 0.0);
+/// ```
+#[cfg(feature = "std")]
+pub fn entropy_bits_estimate(ids: &[Sulid]) -> f64 {
+    if ids.is_empty() {
+        return 0.0;
+    }
+    (0..Sulid::RAND_BITS)
+        .map(|bit| {
+            let ones = ids.iter().filter(|id| id.random() >> bit & 1 == 1).count();
+            let p = ones as f64 / ids.len() as f64;
+            if p == 0.0 || p == 1.0 {
+                0.0
+            } else {
+                -p * p.log2() - (1.0 - p) * (1.0 - p).log2()
+            }
+        })
+        .sum()
+}
+
+/// Merges individually sorted streams of Sulids into one sorted stream.
+///
+/// This is a lazy k-way merge: it only holds the next Sulid of each stream in memory.
+/// Equal Sulids are yielded in the order of their streams. Each stream must be sorted in
+/// ascending order; otherwise the output is not sorted either.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let a = [Sulid::from_u128(1), Sulid::from_u128(4)];
+/// let b = [Sulid::from_u128(2), Sulid::from_u128(3)];
+/// let merged: Vec<_> = sulid::merge_sorted(vec![a.into_iter(), b.into_iter()]).collect();
+/// assert_eq!(merged, [1, 2, 3, 4].map(Sulid::from_u128));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn merge_sorted<I>(mut streams: Vec<I>) -> impl Iterator<Item = Sulid>
+where
+    I: Iterator<Item = Sulid>,
+{
+    let mut heads: BinaryHeap<_> = streams
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(index, stream)| Some(Reverse((stream.next()?, index))))
+        .collect();
+    core::iter::from_fn(move || {
+        let Reverse((sulid, index)) = heads.pop()?;
+        if let Some(next) = streams[index].next() {
+            heads.push(Reverse((next, index)));
+        }
+        Some(sulid)
+    })
+}
+
+/// Checks whether all given Sulids carry the same combined 10-bit worker ID.
+///
+/// An empty or single-element slice is considered uniform.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [Sulid::v2_from_parts(1, 1, 7), Sulid::v2_from_parts(2, 2, 7)];
+/// assert!(sulid::all_same_worker(&ids));
+/// ```
+pub fn all_same_worker(ids: &[Sulid]) -> bool {
+    ids.windows(2)
+        .all(|w| w[0].v2_worker_id() == w[1].v2_worker_id())
+}
+
+/// Partitions the given Sulids by their combined 10-bit worker ID.
+///
+/// Within each group, the Sulids keep their order from the input slice.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let ids = [
+///     Sulid::v2_from_parts(1, 1, 7),
+///     Sulid::v2_from_parts(2, 2, 8),
+///     Sulid::v2_from_parts(3, 3, 7),
+/// ];
+/// let groups = sulid::group_by_worker(&ids);
+/// assert_eq!(groups[&7], [ids[0], ids[2]]);
+/// assert_eq!(groups[&8], [ids[1]]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn group_by_worker(ids: &[Sulid]) -> BTreeMap<u16, Vec<Sulid>> {
+    let mut groups = BTreeMap::<u16, Vec<Sulid>>::new();
+    for &id in ids {
+        groups.entry(id.v2_worker_id()).or_default().push(id);
+    }
+    groups
+}
+
+/// A wrapper that displays a slice of Sulids as a separated list without allocating.
+///
+/// # Example
+/// ```rust
+/// use sulid::{DisplaySlice, Sulid};
+///
+/// let ids = [Sulid::from_u128(1), Sulid::from_u128(2)];
+/// assert_eq!(
+///     DisplaySlice::new(&ids).to_string(),
+///     "00000000000000000000000001, 00000000000000000000000002"
+/// );
+/// assert_eq!(
+///     DisplaySlice::with_separator(&ids, "|").to_string(),
+///     "00000000000000000000000001|00000000000000000000000002"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DisplaySlice<'a> {
+    ids: &'a [Sulid],
+    separator: &'a str,
+}
+
+impl<'a> DisplaySlice<'a> {
+    /// Creates a wrapper that separates the Sulids with `", "`.
+    pub fn new(ids: &'a [Sulid]) -> Self {
+        Self::with_separator(ids, ", ")
+    }
+
+    /// Creates a wrapper that separates the Sulids with the given separator.
+    pub fn with_separator(ids: &'a [Sulid], separator: &'a str) -> Self {
+        DisplaySlice { ids, separator }
+    }
+}
+
+impl fmt::Display for DisplaySlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, id) in self.ids.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            fmt::Display::fmt(id, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combine_worker_id;
+
+    #[test]
+    fn sorted_slice_is_monotonic() {
+        let ids = [
+            Sulid::v1_from_parts(1, 1, 1, 1),
+            Sulid::v1_from_parts(1, 2, 1, 1),
+            Sulid::v1_from_parts(2, 0, 1, 1),
+        ];
+        assert!(is_monotonic(&ids));
+        assert_eq!(first_non_monotonic(&ids), None);
+    }
+
+    #[test]
+    fn unsorted_slice_reports_first_index() {
+        let ids = [
+            Sulid::from_u128(1),
+            Sulid::from_u128(2),
+            Sulid::from_u128(2),
+            Sulid::from_u128(1),
+        ];
+        assert!(!is_monotonic(&ids));
+        assert_eq!(first_non_monotonic(&ids), Some(2));
+    }
+
+    #[test]
+    fn empty_slice_is_monotonic() {
+        assert!(is_monotonic(&[]));
+        assert_eq!(first_non_monotonic(&[]), None);
+    }
+
+    #[test]
+    fn sorts_shuffled_batch() {
+        let mut ids = [
+            Sulid::v1_from_parts(2, 5, 1, 1),
+            Sulid::v1_from_parts(1, 9, 3, 1),
+            Sulid::v1_from_parts(3, 0, 0, 0),
+            Sulid::v1_from_parts(1, 9, 2, 7),
+            Sulid::v1_from_parts(2, 5, 1, 1),
+            Sulid::nil(),
+        ];
+        assert!(!is_sorted(&ids));
+
+        let mut expected = ids;
+        expected.sort();
+        sort_unstable(&mut ids);
+        assert!(is_sorted(&ids));
+        assert_eq!(ids, expected);
+        assert!(is_sorted(&[]));
+    }
+
+    #[test]
+    fn uniform_worker_slice() {
+        let ids = [
+            Sulid::v1_from_parts(1, 1, 2, 3),
+            Sulid::v2_from_parts(2, 9, combine_worker_id(2, 3)),
+            Sulid::v1_from_parts(3, 4, 2, 3),
+        ];
+        assert!(all_same_worker(&ids));
+        assert!(all_same_worker(&[]));
+
+        #[cfg(feature = "std")]
+        {
+            let groups = group_by_worker(&ids);
+            assert_eq!(groups.len(), 1);
+            assert_eq!(groups[&combine_worker_id(2, 3)], ids);
+        }
+    }
+
+    #[test]
+    fn mixed_worker_slice() {
+        let ids = [
+            Sulid::v2_from_parts(1, 1, 5),
+            Sulid::v2_from_parts(2, 1, 1023),
+            Sulid::v2_from_parts(3, 1, 5),
+            Sulid::v2_from_parts(4, 1, 0),
+        ];
+        assert!(!all_same_worker(&ids));
+
+        #[cfg(feature = "std")]
+        {
+            let groups = group_by_worker(&ids);
+            let keys: Vec<_> = groups.keys().copied().collect();
+            assert_eq!(keys, [0, 5, 1023]);
+            assert_eq!(groups[&0], [ids[3]]);
+            assert_eq!(groups[&5], [ids[0], ids[2]]);
+            assert_eq!(groups[&1023], [ids[1]]);
+            assert!(group_by_worker(&[]).is_empty());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn finds_duplicates_in_sorted_slice() {
+        let a = Sulid::v2_from_parts(1, 1, 1);
+        let b = Sulid::v2_from_parts(1, 2, 1);
+        let c = Sulid::v2_from_parts(2, 0, 1);
+
+        assert!(find_duplicates(&[]).is_empty());
+        assert!(find_duplicates(&[a, b, c]).is_empty());
+        assert_eq!(find_duplicates(&[a, b, b, c]), [b]);
+        assert_eq!(find_duplicates(&[a, a, a, b, c, c]), [a, c]);
+
+        // Unsorted input: the non-adjacent duplicate of `a` goes unnoticed.
+        assert!(find_duplicates(&[a, b, a]).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn measures_inter_arrival_gaps() {
+        let ids = [
+            Sulid::v1_from_parts(1_000, 5, 1, 1),
+            Sulid::v1_from_parts(1_000, 6, 1, 1),
+            Sulid::v1_from_parts(1_001, 0, 1, 1),
+            Sulid::v1_from_parts(1_500, 0, 1, 1),
+            Sulid::v1_from_parts(61_500, 0, 1, 1),
+        ];
+        assert_eq!(inter_arrival_ms(&ids), [0, 1, 499, 60_000]);
+        assert!(inter_arrival_ms(&ids[..1]).is_empty());
+        assert!(inter_arrival_ms(&[]).is_empty());
+        assert_eq!(inter_arrival_ms(&[ids[3], ids[0]]), [0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn estimates_random_entropy() {
+        let random: Vec<_> = (0..1000).map(|_| Sulid::v2_new(1)).collect();
+        let high = entropy_bits_estimate(&random);
+        assert!(high > 69.0 && high <= 70.0, "{}", high);
+
+        let constant = vec![Sulid::v2_from_parts(1, 0x2A_DEAD_BEEF, 1); 1000];
+        assert_eq!(entropy_bits_estimate(&constant), 0.0);
+
+        // A counter only exercises its low bits.
+        let counter: Vec<_> = (0..1024).map(|i| Sulid::v2_from_parts(1, i, 1)).collect();
+        assert!((entropy_bits_estimate(&counter) - 10.0).abs() < 1e-9);
+
+        assert_eq!(entropy_bits_estimate(&[]), 0.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merges_sorted_streams() {
+        let a: Vec<_> = [1, 4, 7, 10]
+            .map(|ts| Sulid::v2_from_parts(ts, 0, 1))
+            .into();
+        let b: Vec<_> = [2, 4, 8].map(|ts| Sulid::v2_from_parts(ts, 0, 2)).into();
+        let c: Vec<_> = [0, 3, 11, 12]
+            .map(|ts| Sulid::v2_from_parts(ts, 0, 3))
+            .into();
+
+        let merged: Vec<_> = merge_sorted(vec![a.iter().copied(), b.iter().copied()]).collect();
+        assert_eq!(merged.len(), 7);
+        assert!(is_monotonic(&merged));
+
+        let merged: Vec<_> = merge_sorted(vec![
+            a.clone().into_iter(),
+            b.clone().into_iter(),
+            c.clone().into_iter(),
+        ])
+        .collect();
+        let mut expected = [a, b, c].concat();
+        expected.sort();
+        assert_eq!(merged, expected);
+
+        let duplicate = [Sulid::from_u128(5)];
+        let merged: Vec<_> =
+            merge_sorted(vec![duplicate.iter().copied(), duplicate.iter().copied()]).collect();
+        assert_eq!(merged, [duplicate[0], duplicate[0]]);
+        assert!(is_sorted(&merged));
+
+        let empty: Vec<std::vec::IntoIter<Sulid>> = Vec::new();
+        assert_eq!(merge_sorted(empty).count(), 0);
+        assert_eq!(merge_sorted(vec![[].iter().copied()]).count(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn displays_separated_slice() {
+        let ids = [
+            Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap(),
+            Sulid::nil(),
+            Sulid::from_string("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(),
+        ];
+        assert_eq!(
+            DisplaySlice::new(&ids).to_string(),
+            "01D39ZY06FGSCTVN4T2V9PKHFZ, 00000000000000000000000000, 7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(
+            DisplaySlice::with_separator(&ids, ",").to_string(),
+            "01D39ZY06FGSCTVN4T2V9PKHFZ,00000000000000000000000000,7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(DisplaySlice::new(&[]).to_string(), "");
+    }
+}
@@ -23,7 +23,7 @@
 //! assert_eq!(sulid, res.unwrap());
 //! ```
 
-use crate::{DecodeError, ULID_LEN};
+use crate::{DecodeError, EncodeError, ULID_LEN};
 use core::convert::TryFrom;
 use core::fmt;
 use core::str::FromStr;
@@ -38,6 +38,16 @@ macro_rules! bitmask {
 // Allow other modules to use the macro
 pub(crate) use bitmask;
 
+/// The number of leading Crockford Base32 characters that encode the timestamp.
+const TIME_PREFIX_LEN: usize = 10;
+
+/// The number of characters of the base64url form.
+const BASE64URL_LEN: usize = 22;
+
+/// The base64url alphabet of RFC 4648.
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
 /// A Sulid is a unique 128-bit lexicographically sortable identifier
 ///
 /// Canonically, it is represented as a 26 character Crockford Base32 encoded
@@ -60,6 +70,18 @@ impl Sulid {
     pub const MACHINE_BITS: u8 = 5;
     /// The number of bits for worker ID, which is a combination of data_center_id and machine_id.
     pub const WORKER_BITS: u8 = 10;
+    /// The number of low bits of the random portion holding the checksum of a
+    /// checksummed Sulid, see [`Sulid::with_checksum`].
+    #[cfg(feature = "checksum")]
+    pub const CHECKSUM_BITS: u8 = 8;
+    /// The nil Sulid with all 128 bits zero, equal to [`Sulid::nil`].
+    ///
+    /// Being a constant, it can be used as a pattern, e.g. `match id { Sulid::NIL => .. }`.
+    pub const NIL: Sulid = Sulid::nil();
+    /// The smallest Sulid, equal to [`Sulid::nil`].
+    pub const MIN: Sulid = Sulid::nil();
+    /// The largest Sulid, with all 128 bits set.
+    pub const MAX: Sulid = Sulid(Ulid(u128::MAX));
 
     /// Create a Sulid from integer representation.
     pub fn from_u128(u: u128) -> Self {
@@ -98,23 +120,8 @@ impl Sulid {
         let bitmask_machine_id: u8 = bitmask!(Self::MACHINE_BITS => u8);
 
         #[cfg(feature = "assert")]
-        {
-            assert!(
-                timestamp_ms <= bitmask_timestamp_ms,
-                "timestamp_ms must be in the range 0-281474976710655"
-            );
-            assert!(
-                random <= bitmask_random,
-                "random must be in the range 0-1180591620717411303423"
-            );
-            assert!(
-                data_center_id <= bitmask_data_center_id,
-                "data_center_id must be in the range 0-31"
-            );
-            assert!(
-                machine_id <= bitmask_machine_id,
-                "machine_id must be in the range 0-31"
-            );
+        if let Err(err) = Self::v1_check_parts(timestamp_ms, random, data_center_id, machine_id) {
+            panic!("{}", err.message());
         }
 
         let time_part = (timestamp_ms & bitmask_timestamp_ms) as u128;
@@ -151,19 +158,8 @@ impl Sulid {
         let bitmask_worker_id: u16 = bitmask!(Self::WORKER_BITS => u16);
 
         #[cfg(feature = "assert")]
-        {
-            assert!(
-                timestamp_ms <= bitmask_timestamp_ms,
-                "timestamp_ms must be in the range 0-281474976710655"
-            );
-            assert!(
-                random <= bitmask_random,
-                "random must be in the range 0-1180591620717411303423"
-            );
-            assert!(
-                worker_id <= bitmask_worker_id,
-                "worker_id must be in the range 0-1023"
-            );
+        if let Err(err) = Self::v2_check_parts(timestamp_ms, random, worker_id) {
+            panic!("{}", err.message());
         }
 
         let time_part = (timestamp_ms & bitmask_timestamp_ms) as u128;
@@ -177,11 +173,144 @@ impl Sulid {
         ))
     }
 
+    /// Create a Sulid from separated parts, rejecting out-of-range values.
+    ///
+    /// Unlike [`Sulid::v1_from_parts`], no bits are discarded: an [`IdRangeError`]
+    /// identifying the first offending field is returned instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{IdRangeError, Sulid};
+    ///
+    /// assert_eq!(Sulid::v1_from_parts_checked(1, 1, 1, 1), Ok(Sulid::v1_from_parts(1, 1, 1, 1)));
+    /// assert_eq!(Sulid::v1_from_parts_checked(1, 1, 32, 1), Err(IdRangeError::DataCenterId));
+    /// ```
+    #[inline]
+    pub const fn v1_from_parts_checked(
+        timestamp_ms: u64,
+        random: u128,
+        data_center_id: u8,
+        machine_id: u8,
+    ) -> Result<Sulid, IdRangeError> {
+        match Self::v1_check_parts(timestamp_ms, random, data_center_id, machine_id) {
+            Ok(()) => Ok(Self::v1_from_parts(
+                timestamp_ms,
+                random,
+                data_center_id,
+                machine_id,
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Create a Sulid from separated parts, rejecting out-of-range values.
+    ///
+    /// Unlike [`Sulid::v2_from_parts`], no bits are discarded: an [`IdRangeError`]
+    /// identifying the first offending field is returned instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{IdRangeError, Sulid};
+    ///
+    /// assert_eq!(Sulid::v2_from_parts_checked(1, 1, 1), Ok(Sulid::v2_from_parts(1, 1, 1)));
+    /// assert_eq!(Sulid::v2_from_parts_checked(1, 1, 1024), Err(IdRangeError::WorkerId));
+    /// ```
+    #[inline]
+    pub fn v2_from_parts_checked(
+        timestamp_ms: u64,
+        random: u128,
+        worker_id: u16,
+    ) -> Result<Sulid, IdRangeError> {
+        Self::v2_check_parts(timestamp_ms, random, worker_id)?;
+        Ok(Self::v2_from_parts(timestamp_ms, random, worker_id))
+    }
+
+    /// Create a Sulid from separated parts, with the worker ID given as a high and a low
+    /// component
+    ///
+    /// The 10-bit worker ID is treated as a hierarchical namespace: its top `high_bits`
+    /// bits hold `high` (e.g. a region) and the remaining bits hold `low` (e.g. a node).
+    /// This is the inverse of [`Sulid::worker_split`].
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `high_bits` exceeds 10.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// // A 3-bit region and a 7-bit node.
+    /// let sulid = Sulid::v2_from_parts_hierarchical(1, 2, 5, 100, 3);
+    /// assert_eq!(sulid.v2_worker_id(), (5 << 7) | 100);
+    /// assert_eq!(sulid.worker_split(3), (5, 100));
+    /// ```
+    pub fn v2_from_parts_hierarchical(
+        timestamp_ms: u64,
+        random: u128,
+        high: u16,
+        low: u16,
+        high_bits: u8,
+    ) -> Sulid {
+        assert!(
+            high_bits <= Self::WORKER_BITS,
+            "high_bits must be in the range 0-10"
+        );
+        let low_bits = Self::WORKER_BITS - high_bits;
+        let worker_id =
+            ((high & bitmask!(high_bits => u16)) << low_bits) | (low & bitmask!(low_bits => u16));
+        Self::v2_from_parts(timestamp_ms, random, worker_id)
+    }
+
+    /// Checks that the given v1 parts fit into their fields.
+    const fn v1_check_parts(
+        timestamp_ms: u64,
+        random: u128,
+        data_center_id: u8,
+        machine_id: u8,
+    ) -> Result<(), IdRangeError> {
+        if timestamp_ms > bitmask!(Self::TIME_BITS => u64) {
+            Err(IdRangeError::Timestamp)
+        } else if random > bitmask!(Self::RAND_BITS => u128) {
+            Err(IdRangeError::Random)
+        } else if data_center_id > bitmask!(Self::DATA_CENTER_BITS => u8) {
+            Err(IdRangeError::DataCenterId)
+        } else if machine_id > bitmask!(Self::MACHINE_BITS => u8) {
+            Err(IdRangeError::MachineId)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the given v2 parts fit into their fields.
+    const fn v2_check_parts(
+        timestamp_ms: u64,
+        random: u128,
+        worker_id: u16,
+    ) -> Result<(), IdRangeError> {
+        if timestamp_ms > bitmask!(Self::TIME_BITS => u64) {
+            Err(IdRangeError::Timestamp)
+        } else if random > bitmask!(Self::RAND_BITS => u128) {
+            Err(IdRangeError::Random)
+        } else if worker_id > bitmask!(Self::WORKER_BITS => u16) {
+            Err(IdRangeError::WorkerId)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Creates a Sulid from a Crockford Base32 encoded string
     ///
     /// An DecodeError will be returned when the given string is not formatted
     /// properly.
     ///
+    /// 26 characters hold 130 bits, so a first character above `7` encodes bits beyond
+    /// the 128-bit value. These bits are silently discarded, e.g. `8ZZZZZZZZZZZZZZZZZZZZZZZZZ`
+    /// decodes to the same Sulid as `0ZZZZZZZZZZZZZZZZZZZZZZZZZ`. Use
+    /// [`Sulid::from_string_strict`] to reject such strings.
+    ///
     /// # Example
     /// ```rust
     /// use sulid::Sulid;
@@ -200,178 +329,1605 @@ impl Sulid {
         }
     }
 
-    /// The 'nil Sulid'.
+    /// Creates a Sulid from a Crockford Base32 encoded string, rejecting values that
+    /// don't fit into 128 bits
     ///
-    /// The nil Sulid is special form of Sulid that is specified to have
-    /// all 128 bits set to zero.
+    /// Unlike [`Sulid::from_string`], which silently discards the overflowing bits, a
+    /// [`StrictDecodeError::Overflow`] is returned when the first character is above `7`,
+    /// as required by the ULID specification.
     ///
     /// # Example
     /// ```rust
-    /// use sulid::Sulid;
-    ///
-    /// let sulid = Sulid::nil();
+    /// use sulid::{StrictDecodeError, Sulid};
     ///
+    /// assert!(Sulid::from_string_strict("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").is_ok());
     /// assert_eq!(
-    ///     sulid.to_string(),
-    ///     "00000000000000000000000000"
+    ///     Sulid::from_string_strict("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+    ///     Err(StrictDecodeError::Overflow)
     /// );
     /// ```
-    #[inline]
-    pub const fn nil() -> Sulid {
-        Sulid(Ulid::nil())
+    pub const fn from_string_strict(encoded: &str) -> Result<Sulid, StrictDecodeError> {
+        match Sulid::from_string(encoded) {
+            Ok(sulid) => match encoded.as_bytes()[0] {
+                b'0'..=b'7' => Ok(sulid),
+                _ => Err(StrictDecodeError::Overflow),
+            },
+            Err(DecodeError::InvalidLength) => Err(StrictDecodeError::InvalidLength),
+            Err(DecodeError::InvalidChar) => Err(StrictDecodeError::InvalidChar),
+        }
     }
 
-    /// Gets the timestamp section of this sulid
+    /// Checks that a string has the length and alphabet of an encoded Sulid, without
+    /// decoding it
+    ///
+    /// This accepts exactly the strings [`Sulid::from_string`] accepts, case-insensitively,
+    /// but skips computing the value, so obviously malformed input is rejected cheaply.
+    /// Like decoding, it reports a wrong length before an invalid character.
     ///
     /// # Example
     /// ```rust
-    /// # #[cfg(feature = "std")] {
-    /// use std::time::{SystemTime, Duration};
-    /// use sulid::Sulid;
+    /// use sulid::{DecodeError, Sulid};
     ///
-    /// let dt = SystemTime::now();
-    /// let sulid = Sulid::v1_from_parts(dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis() as u64, 1, 1, 1);
-    /// assert_eq!(u128::from(sulid.timestamp_ms()), dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis());
-    ///
-    /// let sulid = Sulid::v2_from_parts(dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis() as u64, 1, 1);
-    /// assert_eq!(u128::from(sulid.timestamp_ms()), dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis());
-    /// # }
+    /// assert_eq!(Sulid::quick_validate("01D39ZY06FGSCTVN4T2V9PKHFZ"), Ok(()));
+    /// assert_eq!(Sulid::quick_validate("01D39ZY06FGSCTVN4T2V9PKHF"), Err(DecodeError::InvalidLength));
+    /// assert_eq!(Sulid::quick_validate("01D39ZY06FGSCTUN4T2V9PKHFZ"), Err(DecodeError::InvalidChar));
     /// ```
-    pub const fn timestamp_ms(&self) -> u64 {
-        (self.0 .0 >> (Self::RAND_BITS + Self::DATA_CENTER_BITS + Self::MACHINE_BITS)) as u64
+    pub const fn quick_validate(encoded: &str) -> Result<(), DecodeError> {
+        let bytes = encoded.as_bytes();
+        if bytes.len() != ULID_LEN {
+            return Err(DecodeError::InvalidLength);
+        }
+        let mut i = 0;
+        while i < ULID_LEN {
+            match bytes[i].to_ascii_uppercase() {
+                b'0'..=b'9' | b'A'..=b'H' | b'J' | b'K' | b'M' | b'N' | b'P'..=b'T' => {}
+                b'V'..=b'Z' => {}
+                _ => return Err(DecodeError::InvalidChar),
+            }
+            i += 1;
+        }
+        Ok(())
     }
 
-    /// Gets the random section of this sulid
+    /// Creates a Sulid from a Crockford Base32 encoded string, reporting where decoding
+    /// failed
+    ///
+    /// This accepts the same strings as [`Sulid::from_string`], but the error carries the
+    /// position of the first invalid character or the expected and actual length, which
+    /// makes for more helpful error messages for clients submitting malformed IDs.
+    /// Invalid characters are reported before a wrong length.
     ///
     /// # Example
     /// ```rust
-    /// use sulid::Sulid;
-    ///
-    /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
-    /// let sulid = Sulid::from_string(text).unwrap();
-    /// let sulid_next = sulid.increment().unwrap();
+    /// use sulid::{Sulid, SulidDecodeError};
     ///
-    /// assert_eq!(sulid.random() + 1, sulid_next.random());
+    /// let err = Sulid::from_string_verbose("01D39ZY06FGSCTUN4T2V9PKHFZ").unwrap_err();
+    /// assert_eq!(err, SulidDecodeError::InvalidChar { position: 14, character: 'U' });
+    /// assert_eq!(err.to_string(), "invalid character 'U' at position 14");
     /// ```
-    pub const fn random(&self) -> u128 {
-        (self.0 .0 >> (Self::DATA_CENTER_BITS + Self::MACHINE_BITS))
-            & bitmask!(Self::RAND_BITS => u128)
-    }
-
-    /// Gets the data center ID portion of this sulid
-    /// NOTE: It is only meaningful for v1.
-    pub const fn v1_data_center_id(&self) -> u8 {
-        ((self.0 .0 >> Self::MACHINE_BITS) & bitmask!(Self::DATA_CENTER_BITS => u128)) as u8
+    pub fn from_string_verbose(encoded: &str) -> Result<Sulid, SulidDecodeError> {
+        let is_crockford = |c: char| {
+            c.is_ascii_alphanumeric() && !matches!(c.to_ascii_uppercase(), 'I' | 'L' | 'O' | 'U')
+        };
+        if let Some((position, character)) =
+            encoded.chars().enumerate().find(|&(_, c)| !is_crockford(c))
+        {
+            return Err(SulidDecodeError::InvalidChar {
+                position,
+                character,
+            });
+        }
+        Sulid::from_string(encoded).map_err(|_| SulidDecodeError::InvalidLength {
+            expected: ULID_LEN,
+            actual: encoded.len(),
+        })
     }
 
-    /// Gets the machine ID portion of this sulid
-    /// NOTE: It is only meaningful for v1.
-    pub const fn v1_machine_id(&self) -> u8 {
-        (self.0 .0 & bitmask!(Self::MACHINE_BITS => u128)) as u8
+    /// Parses a Sulid from the base-10 representation of its 128-bit value
+    ///
+    /// This is the inverse of [`Sulid::to_decimal`], for legacy systems that store 128-bit
+    /// IDs as decimal strings. Leading zeros are accepted.
+    ///
+    /// A [`DecodeError::InvalidChar`] is returned if `s` contains anything but ASCII
+    /// digits, and a [`DecodeError::InvalidLength`] if it is empty or its value doesn't fit
+    /// into 128 bits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{DecodeError, Sulid};
+    ///
+    /// assert_eq!(Sulid::from_decimal("42"), Ok(Sulid::from_u128(42)));
+    /// assert_eq!(Sulid::from_decimal("-1"), Err(DecodeError::InvalidChar));
+    /// ```
+    pub const fn from_decimal(s: &str) -> Result<Sulid, DecodeError> {
+        let digits = s.as_bytes();
+        if digits.is_empty() {
+            return Err(DecodeError::InvalidLength);
+        }
+        let mut value: u128 = 0;
+        let mut i = 0;
+        while i < digits.len() {
+            let digit = digits[i];
+            if !digit.is_ascii_digit() {
+                return Err(DecodeError::InvalidChar);
+            }
+            value = match value.checked_mul(10) {
+                Some(value) => match value.checked_add((digit - b'0') as u128) {
+                    Some(value) => value,
+                    None => return Err(DecodeError::InvalidLength),
+                },
+                None => return Err(DecodeError::InvalidLength),
+            };
+            i += 1;
+        }
+        Ok(Sulid(Ulid(value)))
     }
 
-    /// Gets the worker ID portion of this sulid
-    /// NOTE: It is only meaningful for v2.
-    pub const fn v2_worker_id(&self) -> u16 {
-        (self.0 .0 & bitmask!(Self::WORKER_BITS => u128)) as u16
+    /// Assembles a V1 Sulid from the string forms of its components
+    ///
+    /// This suits storage schemas that keep the parts of a Sulid in separate columns.
+    /// Each component may be written in decimal or as `0x`-prefixed hexadecimal, e.g.
+    /// the random number as `0x3ff`. Signs, whitespace and empty strings are rejected.
+    ///
+    /// Unlike [`Sulid::v1_from_parts`], out-of-range values are not truncated: a
+    /// [`ParseError::OutOfRange`] names the offending field. Malformed components are
+    /// reported by the `Invalid*` variant of the first malformed field.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{IdRangeError, ParseError, Sulid};
+    ///
+    /// let sulid = Sulid::from_component_strings("1700000000000", "0x2a", "3", "4").unwrap();
+    /// assert_eq!(sulid, Sulid::v1_from_parts(1_700_000_000_000, 42, 3, 4));
+    ///
+    /// let err = Sulid::from_component_strings("1700000000000", "42", "three", "4");
+    /// assert_eq!(err, Err(ParseError::InvalidDataCenterId));
+    /// let err = Sulid::from_component_strings("1700000000000", "42", "32", "4");
+    /// assert_eq!(err, Err(ParseError::OutOfRange(IdRangeError::DataCenterId)));
+    /// ```
+    pub fn from_component_strings(
+        timestamp_ms: &str,
+        random: &str,
+        data_center_id: &str,
+        machine_id: &str,
+    ) -> Result<Sulid, ParseError> {
+        let timestamp_ms = parse_component(timestamp_ms).ok_or(ParseError::InvalidTimestamp)?;
+        let random = parse_component(random).ok_or(ParseError::InvalidRandom)?;
+        let data_center_id =
+            parse_component(data_center_id).ok_or(ParseError::InvalidDataCenterId)?;
+        let machine_id = parse_component(machine_id).ok_or(ParseError::InvalidMachineId)?;
+        Ok(Sulid::v1_from_parts_checked(
+            u64::try_from(timestamp_ms).map_err(|_| IdRangeError::Timestamp)?,
+            random,
+            u8::try_from(data_center_id).map_err(|_| IdRangeError::DataCenterId)?,
+            u8::try_from(machine_id).map_err(|_| IdRangeError::MachineId)?,
+        )?)
     }
 
-    /// Creates a Crockford Base32 encoded string that represents this Sulid
+    /// Finds the first Sulid embedded in a larger string, e.g. a log line
+    ///
+    /// Every 26-character window of `haystack` is tried from left to right, and the first
+    /// one that decodes as a canonical Sulid (see [`Sulid::from_string_strict`]) is
+    /// returned together with its byte offset. Windows may overlap, so in a longer run of
+    /// Crockford Base32 characters the leftmost valid window wins. No word boundaries are
+    /// required around the match.
     ///
     /// # Example
     /// ```rust
     /// use sulid::Sulid;
     ///
-    /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
-    /// let sulid = Sulid::from_string(text).unwrap();
-    ///
-    /// let mut buf = [0; sulid::ULID_LEN];
-    /// let new_text = sulid.array_to_str(&mut buf);
-    ///
-    /// assert_eq!(new_text, text);
+    /// let line = "GET /orders/01D39ZY06FGSCTVN4T2V9PKHFZ 200";
+    /// assert_eq!(Sulid::scan(line), Some((Sulid::example(), 12)));
+    /// assert_eq!(Sulid::scan("no id here"), None);
     /// ```
-    pub fn array_to_str<'buf>(&self, buf: &'buf mut [u8; ULID_LEN]) -> &'buf mut str {
-        self.0.array_to_str(buf)
+    pub fn scan(haystack: &str) -> Option<(Sulid, usize)> {
+        (0..=haystack.len().checked_sub(ULID_LEN)?).find_map(|offset| {
+            let candidate = haystack.get(offset..offset + ULID_LEN)?;
+            Sulid::from_string_strict(candidate)
+                .ok()
+                .map(|sulid| (sulid, offset))
+        })
     }
 
-    /// Test if the Sulid is nil
+    /// Decodes only the timestamp from the leading characters of a Crockford Base32 string
+    ///
+    /// Just the first 10 characters, which encode the 48-bit timestamp, are validated;
+    /// anything after them is ignored. This allows recovering the time component of
+    /// truncated or partially corrupted Sulids, e.g. when scanning logs.
+    ///
+    /// A [`DecodeError::InvalidLength`] is returned when the string is shorter than 10
+    /// characters, and a [`DecodeError::InvalidChar`] when one of those characters is not
+    /// valid Crockford Base32.
     ///
     /// # Example
     /// ```rust
     /// use sulid::Sulid;
     ///
-    /// let sulid = Sulid::from_u128(1);
-    /// assert!(!sulid.is_nil());
-    ///
-    /// let nil = Sulid::nil();
-    /// assert!(nil.is_nil());
+    /// let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    /// assert_eq!(Sulid::timestamp_from_str_prefix("01D39ZY06F#damaged"), Ok(sulid.timestamp_ms()));
     /// ```
-    #[inline]
-    pub const fn is_nil(&self) -> bool {
-        self.0.is_nil()
+    pub fn timestamp_from_str_prefix(s: &str) -> Result<u64, DecodeError> {
+        let prefix = s
+            .as_bytes()
+            .get(..TIME_PREFIX_LEN)
+            .ok_or(DecodeError::InvalidLength)?;
+        let mut buffer = [b'0'; ULID_LEN];
+        buffer[..TIME_PREFIX_LEN].copy_from_slice(prefix);
+        let padded = core::str::from_utf8(&buffer).map_err(|_| DecodeError::InvalidChar)?;
+        Sulid::from_string(padded).map(|sulid| sulid.timestamp_ms())
     }
 
-    /// Increment the random number, make sure that the ts millis stays the same
-    pub const fn increment(&self) -> Option<Sulid> {
-        const MAX_RANDOM: u128 = bitmask!(Sulid::RAND_BITS => u128);
+    /// Creates a Sulid from a string in any of the supported encodings
+    ///
+    /// The encoding is chosen from the length and characters of the string, in this order:
+    ///
+    /// 1. 26 characters: Crockford Base32, as in [`Sulid::from_string`].
+    /// 2. 32 characters: hexadecimal (case-insensitive, no `0x` prefix).
+    /// 3. Otherwise: the decimal representation of the `u128`, as in [`Sulid::from_decimal`].
+    ///
+    /// A 26-digit decimal string is thus decoded as Crockford Base32 and a 32-digit
+    /// decimal string as hexadecimal.
+    ///
+    /// A [`DecodeError::InvalidChar`] is returned when the string contains characters that
+    /// are invalid for the chosen encoding, and a [`DecodeError::InvalidLength`] when it is
+    /// empty or its decimal value doesn't fit into 128 bits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    /// assert_eq!(Sulid::from_any("01D39ZY06FGSCTVN4T2V9PKHFZ"), Ok(sulid));
+    /// assert_eq!(Sulid::from_any("0168d3ff00cf8659add49a16d369c5ff"), Ok(sulid));
+    /// assert_eq!(Sulid::from_any("1873526660931231682191479120471901695"), Ok(sulid));
+    /// ```
+    pub fn from_any(s: &str) -> Result<Sulid, DecodeError> {
+        const HEX_LEN: usize = 32;
 
-        if ((self.0 .0 >> (Sulid::DATA_CENTER_BITS + Sulid::MACHINE_BITS)) & MAX_RANDOM)
-            == MAX_RANDOM
-        {
-            None
-        } else {
-            Some(Sulid(Ulid(
-                self.0 .0 + (1 << (Sulid::DATA_CENTER_BITS + Sulid::MACHINE_BITS)),
-            )))
+        match s.len() {
+            ULID_LEN => Sulid::from_string(s),
+            HEX_LEN => {
+                if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(DecodeError::InvalidChar);
+                }
+                u128::from_str_radix(s, 16)
+                    .map(Sulid::from_u128)
+                    .map_err(|_| DecodeError::InvalidChar)
+            }
+            _ => Sulid::from_decimal(s),
         }
     }
 
-    /// Creates a Sulid using the provided bytes array.
+    /// The 'nil Sulid'.
+    ///
+    /// The nil Sulid is special form of Sulid that is specified to have
+    /// all 128 bits set to zero.
     ///
     /// # Example
-    /// ```
+    /// ```rust
     /// use sulid::Sulid;
-    /// let bytes = [0xFF; 16];
     ///
-    /// let sulid = Sulid::from_bytes(bytes);
+    /// let sulid = Sulid::nil();
     ///
     /// assert_eq!(
     ///     sulid.to_string(),
-    ///     "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+    ///     "00000000000000000000000000"
     /// );
     /// ```
     #[inline]
-    pub const fn from_bytes(bytes: [u8; 16]) -> Sulid {
-        Self(Ulid::from_bytes(bytes))
+    pub const fn nil() -> Sulid {
+        Sulid(Ulid::nil())
     }
 
-    /// Returns the bytes of the Sulid in big-endian order.
+    /// A fixed sample Sulid for documentation and tests: `01D39ZY06FGSCTVN4T2V9PKHFZ`
+    ///
+    /// It has the timestamp `1_549_744_931_023` (2019-02-09T20:42:11.023Z), the worker ID
+    /// `511` and is non-nil, so examples can use it without a clock or an RNG.
     ///
     /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::example();
+    /// assert_eq!(sulid.to_string(), "01D39ZY06FGSCTVN4T2V9PKHFZ");
     /// ```
+    pub const fn example() -> Sulid {
+        Sulid(Ulid(0x0168_D3FF_00CF_8659_ADD4_9A16_D369_C5FF))
+    }
+
+    /// Gets the timestamp section of this sulid
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::time::{SystemTime, Duration};
     /// use sulid::Sulid;
     ///
-    /// let text = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
-    /// let sulid = Sulid::from_string(text).unwrap();
+    /// let dt = SystemTime::now();
+    /// let sulid = Sulid::v1_from_parts(dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis() as u64, 1, 1, 1);
+    /// assert_eq!(u128::from(sulid.timestamp_ms()), dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis());
     ///
-    /// assert_eq!(sulid.to_bytes(), [0xFF; 16]);
+    /// let sulid = Sulid::v2_from_parts(dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis() as u64, 1, 1);
+    /// assert_eq!(u128::from(sulid.timestamp_ms()), dt.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO).as_millis());
+    /// # }
     /// ```
-    #[inline]
-    pub const fn to_bytes(&self) -> [u8; 16] {
-        self.0.to_bytes()
+    pub const fn timestamp_ms(&self) -> u64 {
+        (self.0 .0 >> (Self::RAND_BITS + Self::DATA_CENTER_BITS + Self::MACHINE_BITS)) as u64
     }
-}
 
-impl Default for Sulid {
-    fn default() -> Self {
-        Sulid::nil()
+    /// Gets the timestamp of this sulid as a [`TimeKey`] for time-only comparisons
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{Sulid, TimeKey};
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// assert!(sulid.time_key() < TimeKey(1_700_000_000_001));
+    /// assert_eq!(sulid.time_key(), TimeKey(1_700_000_000_000));
+    /// ```
+    pub const fn time_key(&self) -> TimeKey {
+        TimeKey(self.timestamp_ms())
     }
-}
 
-impl From<(u64, u128, u8, u8)> for Sulid {
-    /// NOTE: It is only meaningful for v1.
-    fn from((timestamp_ms, random, data_center_id, machine_id): (u64, u128, u8, u8)) -> Self {
-        Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id)
+    /// Gets the 48-bit timestamp section of this sulid as 6 big-endian bytes
+    ///
+    /// These are the first 6 bytes of [`Sulid::to_bytes`], e.g. for storage keyed on the
+    /// raw timestamp prefix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::example();
+    /// assert_eq!(sulid.timestamp_bytes(), [0x01, 0x68, 0xD3, 0xFF, 0x00, 0xCF]);
+    /// assert_eq!(sulid.timestamp_bytes(), sulid.to_bytes()[..6]);
+    /// ```
+    pub const fn timestamp_bytes(&self) -> [u8; 6] {
+        let bytes = self.timestamp_ms().to_be_bytes();
+        [bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]
+    }
+
+    /// Returns a copy of this sulid with the timestamp section replaced by 6 big-endian bytes
+    ///
+    /// This is the inverse of [`Sulid::timestamp_bytes`]; the random section and worker
+    /// bits are preserved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(0, 42, 7).with_timestamp_bytes([0, 0, 0, 0, 1, 0]);
+    /// assert_eq!(sulid, Sulid::v2_from_parts(256, 42, 7));
+    /// ```
+    pub const fn with_timestamp_bytes(&self, bytes: [u8; 6]) -> Sulid {
+        const SHIFT: u8 = Sulid::RAND_BITS + Sulid::WORKER_BITS;
+        let timestamp_ms = u64::from_be_bytes([
+            0, 0, bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+        ]);
+        Sulid(Ulid(
+            (timestamp_ms as u128) << SHIFT | (self.0 .0 & bitmask!(SHIFT => u128)),
+        ))
+    }
+
+    /// Gets the index of the `bucket_ms`-wide time bucket this sulid falls into
+    ///
+    /// This is `timestamp_ms() / bucket_ms`, e.g. the hour since the Unix epoch for a
+    /// bucket of 3,600,000 ms, and is meant for routing Sulids into time partitions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_ms` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// const HOUR_MS: u64 = 60 * 60 * 1000;
+    /// let sulid = Sulid::v2_from_parts(3 * HOUR_MS + 42, 1, 1);
+    /// assert_eq!(sulid.time_bucket(HOUR_MS), 3);
+    /// ```
+    pub const fn time_bucket(&self, bucket_ms: u64) -> u64 {
+        self.timestamp_ms() / bucket_ms
+    }
+
+    /// Returns a copy of this sulid with the timestamp rounded down to a multiple of
+    /// `resolution_ms`
+    ///
+    /// The random and worker sections are preserved. This reduces the precision of the
+    /// embedded creation time, e.g. to the hour, to de-identify it. A `resolution_ms` of
+    /// zero leaves the sulid unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// const HOUR_MS: u64 = 60 * 60 * 1000;
+    /// let sulid = Sulid::v2_from_parts(3 * HOUR_MS + 42, 1, 1);
+    /// assert_eq!(sulid.quantize_time(HOUR_MS), Sulid::v2_from_parts(3 * HOUR_MS, 1, 1));
+    /// ```
+    pub const fn quantize_time(&self, resolution_ms: u64) -> Sulid {
+        const TIME_SHIFT: u8 = Sulid::RAND_BITS + Sulid::WORKER_BITS;
+
+        if resolution_ms == 0 {
+            return *self;
+        }
+        let timestamp_ms = self.timestamp_ms();
+        let quantized = timestamp_ms - timestamp_ms % resolution_ms;
+        Sulid(Ulid(
+            ((quantized as u128) << TIME_SHIFT) | (self.0 .0 & bitmask!(TIME_SHIFT => u128)),
+        ))
+    }
+
+    /// Gets the smallest and the largest Sulid of a time bucket, both inclusive
+    ///
+    /// The bucket with index `bucket_index` covers the timestamps
+    /// `bucket_index * bucket_ms..(bucket_index + 1) * bucket_ms`, so every Sulid whose
+    /// [`Sulid::time_bucket`] is `bucket_index` lies within the returned bounds. Bounds
+    /// beyond the largest 48-bit timestamp are clamped to [`Sulid::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_ms` is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// const HOUR_MS: u64 = 60 * 60 * 1000;
+    /// let (start, end) = Sulid::bucket_range(3, HOUR_MS);
+    /// assert_eq!(start.timestamp_ms(), 3 * HOUR_MS);
+    /// assert_eq!(end.timestamp_ms(), 4 * HOUR_MS - 1);
+    ///
+    /// let sulid = Sulid::v2_from_parts(3 * HOUR_MS + 42, 1, 1);
+    /// assert!(start <= sulid && sulid <= end);
+    /// ```
+    pub const fn bucket_range(bucket_index: u64, bucket_ms: u64) -> (Sulid, Sulid) {
+        assert!(bucket_ms > 0, "bucket_ms must not be zero");
+        const TIME_SHIFT: u8 = Sulid::RAND_BITS + Sulid::WORKER_BITS;
+        const MAX_TIMESTAMP: u64 = bitmask!(Sulid::TIME_BITS => u64);
+
+        let start_ms = bucket_index.saturating_mul(bucket_ms);
+        if start_ms > MAX_TIMESTAMP {
+            return (Sulid::MAX, Sulid::MAX);
+        }
+        let end_ms = start_ms.saturating_add(bucket_ms - 1);
+        let start = Sulid(Ulid((start_ms as u128) << TIME_SHIFT));
+        let end = if end_ms >= MAX_TIMESTAMP {
+            Sulid::MAX
+        } else {
+            Sulid(Ulid((((end_ms + 1) as u128) << TIME_SHIFT) - 1))
+        };
+        (start, end)
+    }
+
+    /// Gets the random section of this sulid
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+    /// let sulid = Sulid::from_string(text).unwrap();
+    /// let sulid_next = sulid.increment().unwrap();
+    ///
+    /// assert_eq!(sulid.random() + 1, sulid_next.random());
+    /// ```
+    pub const fn random(&self) -> u128 {
+        (self.0 .0 >> (Self::DATA_CENTER_BITS + Self::MACHINE_BITS))
+            & bitmask!(Self::RAND_BITS => u128)
+    }
+
+    /// Gets the position of the random section within the 70-bit random space as a fraction
+    ///
+    /// This is `random() / 2^70`, from `0.0` for a zero random section up to `1.0` for the
+    /// largest one, e.g. for visualizing how random values spread over the keyspace of a
+    /// millisecond. The conversion to `f64` keeps only the top 53 bits, so the largest
+    /// random values round to exactly `1.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 1 << 69, 7);
+    /// assert_eq!(sulid.keyspace_fraction(), 0.5);
+    /// ```
+    pub fn keyspace_fraction(&self) -> f64 {
+        self.random() as f64 / (1u128 << Self::RAND_BITS) as f64
+    }
+
+    /// Gets the top `n` bits of the random section
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` exceeds 70.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, (0xDEAD_BEEF << 38) | 0x1234, 1, 1);
+    /// assert_eq!(sulid.random_high_bits(32), 0xDEAD_BEEF);
+    /// ```
+    pub const fn random_high_bits(&self, n: u8) -> u128 {
+        assert!(n <= Self::RAND_BITS, "n must be in the range 0-70");
+        self.random() >> (Self::RAND_BITS - n)
+    }
+
+    /// Gets the low `n` bits of the random section
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` exceeds 70.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, (0xDEAD_BEEF << 38) | 0x1234, 1, 1);
+    /// assert_eq!(sulid.random_low_bits(38), 0x1234);
+    /// ```
+    pub const fn random_low_bits(&self, n: u8) -> u128 {
+        assert!(n <= Self::RAND_BITS, "n must be in the range 0-70");
+        self.random() & bitmask!(n => u128)
+    }
+
+    /// Returns a copy of this sulid with the top `n` bits of the random section replaced by `value`
+    ///
+    /// The timestamp, the remaining random bits and the worker bits are preserved.
+    ///
+    /// NOTE: Any overflow bits in `value` are discarded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` exceeds 70.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, 0x1234, 1, 1).with_random_high_bits(32, 0xDEAD_BEEF);
+    /// assert_eq!(sulid.random_high_bits(32), 0xDEAD_BEEF);
+    /// assert_eq!(sulid.random_low_bits(38), 0x1234);
+    /// ```
+    pub const fn with_random_high_bits(&self, n: u8, value: u128) -> Sulid {
+        assert!(n <= Self::RAND_BITS, "n must be in the range 0-70");
+        let low_bits = Self::RAND_BITS - n;
+        let shift = low_bits + Self::DATA_CENTER_BITS + Self::MACHINE_BITS;
+        let mask = bitmask!(n => u128) << shift;
+        Sulid(Ulid((self.0 .0 & !mask) | ((value << shift) & mask)))
+    }
+
+    /// Gets the sequence number stored in the top `seq_bits` of the random section
+    ///
+    /// NOTE: It is only meaningful for sulids created by a sequenced generator such as
+    /// [`SulidGenerator::v1_new_sequenced`](crate::SulidGenerator::v1_new_sequenced).
+    /// A `seq_bits` larger than 70 is treated as 70.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, (5 << 54) | 0xABC, 1, 1);
+    /// assert_eq!(sulid.sequence(16), 5);
+    /// ```
+    pub const fn sequence(&self, seq_bits: u8) -> u128 {
+        let seq_bits = if seq_bits > Self::RAND_BITS {
+            Self::RAND_BITS
+        } else {
+            seq_bits
+        };
+        self.random() >> (Self::RAND_BITS - seq_bits)
+    }
+
+    /// Gets the data center ID portion of this sulid
+    /// NOTE: It is only meaningful for v1.
+    pub const fn v1_data_center_id(&self) -> u8 {
+        ((self.0 .0 >> Self::MACHINE_BITS) & bitmask!(Self::DATA_CENTER_BITS => u128)) as u8
+    }
+
+    /// Gets the machine ID portion of this sulid
+    /// NOTE: It is only meaningful for v1.
+    pub const fn v1_machine_id(&self) -> u8 {
+        (self.0 .0 & bitmask!(Self::MACHINE_BITS => u128)) as u8
+    }
+
+    /// Gets the worker ID portion of this sulid
+    /// NOTE: It is only meaningful for v2.
+    pub const fn v2_worker_id(&self) -> u16 {
+        (self.0 .0 & bitmask!(Self::WORKER_BITS => u128)) as u16
+    }
+
+    /// Gets all 10 bits of the worker section, regardless of the version
+    ///
+    /// For V1 Sulids this is the data center ID and machine ID combined, for V2 Sulids the
+    /// worker ID. The bits can also carry arbitrary 10-bit metadata.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// assert_eq!(Sulid::v1_from_parts(1, 2, 3, 4).worker_field(), (3 << 5) | 4);
+    /// assert_eq!(Sulid::v2_from_parts(1, 2, 1023).worker_field(), 1023);
+    /// ```
+    #[inline]
+    pub const fn worker_field(&self) -> u16 {
+        self.v2_worker_id()
+    }
+
+    /// Returns a copy of this sulid with all 10 bits of the worker section replaced
+    ///
+    /// This is the version-neutral counterpart of [`Sulid::v2_with_worker_id`] and
+    /// [`Sulid::v1_with_data_center_and_machine`].
+    ///
+    /// NOTE: Any overflow bits in `value` are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, 2, 3, 4).with_worker_field(0b10101_01010);
+    /// assert_eq!((sulid.v1_data_center_id(), sulid.v1_machine_id()), (0b10101, 0b01010));
+    /// ```
+    #[inline]
+    pub const fn with_worker_field(&self, value: u16) -> Sulid {
+        self.v2_with_worker_id(value)
+    }
+
+    /// Splits the worker ID into a high and a low component at the given boundary
+    ///
+    /// The first component holds the top `high_bits` bits of the 10-bit worker ID and
+    /// the second one the remaining `10 - high_bits` bits, e.g. a 3-bit region and a
+    /// 7-bit node for `high_bits == 3`. With `high_bits == 5` this matches the V1 data
+    /// center and machine IDs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `high_bits` exceeds 10.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1, 2, 0b101_1100100);
+    /// assert_eq!(sulid.worker_split(3), (0b101, 0b1100100));
+    /// assert_eq!(sulid.worker_split(0), (0, 0b101_1100100));
+    /// ```
+    pub const fn worker_split(&self, high_bits: u8) -> (u16, u16) {
+        assert!(
+            high_bits <= Self::WORKER_BITS,
+            "high_bits must be in the range 0-10"
+        );
+        let low_bits = Self::WORKER_BITS - high_bits;
+        let worker_id = self.v2_worker_id();
+        (worker_id >> low_bits, worker_id & bitmask!(low_bits => u16))
+    }
+
+    /// Returns a copy of this sulid with the worker ID replaced
+    ///
+    /// Only the low 10 bits change; the timestamp and random sections are preserved.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1, 2, 3).v2_with_worker_id(1000);
+    /// assert_eq!(sulid, Sulid::v2_from_parts(1, 2, 1000));
+    /// ```
+    pub const fn v2_with_worker_id(&self, worker_id: u16) -> Sulid {
+        const WORKER_MASK: u128 = bitmask!(Sulid::WORKER_BITS => u128);
+        Sulid(Ulid(
+            (self.0 .0 & !WORKER_MASK) | (worker_id as u128 & WORKER_MASK),
+        ))
+    }
+
+    /// Returns a copy of this sulid with the data center ID and machine ID replaced
+    ///
+    /// Only the low 10 bits change; the timestamp and random sections are preserved.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v1_from_parts(1, 2, 3, 4).v1_with_data_center_and_machine(5, 6);
+    /// assert_eq!(sulid, Sulid::v1_from_parts(1, 2, 5, 6));
+    /// ```
+    pub const fn v1_with_data_center_and_machine(
+        &self,
+        data_center_id: u8,
+        machine_id: u8,
+    ) -> Sulid {
+        self.v2_with_worker_id(combine_worker_id(data_center_id, machine_id))
+    }
+
+    /// Returns the smallest Sulid of the given worker, with a zero timestamp and random section
+    ///
+    /// Together with [`Sulid::worker_max`] this brackets every Sulid the worker can produce,
+    /// e.g. as the bounds of a per-worker key-range scan.
+    ///
+    /// NOTE: The worker bits are the least significant bits, so the range between
+    /// `worker_min` and `worker_max` is not exclusive to the worker: it also contains the
+    /// IDs of all other workers, interleaved by timestamp and random section. Filter the
+    /// scanned IDs by [`Sulid::v2_worker_id`] to keep only the worker's own.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// assert!(Sulid::worker_min(7) <= sulid && sulid <= Sulid::worker_max(7));
+    /// ```
+    pub const fn worker_min(worker_id: u16) -> Sulid {
+        Sulid::MIN.v2_with_worker_id(worker_id)
+    }
+
+    /// Returns the largest Sulid of the given worker, with the maximum timestamp and random section
+    ///
+    /// See [`Sulid::worker_min`] for the caveat that other workers' IDs fall into the range too.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    pub const fn worker_max(worker_id: u16) -> Sulid {
+        Sulid::MAX.v2_with_worker_id(worker_id)
+    }
+
+    /// Derives a stable 32-bit seed from this sulid, e.g. for picking a per-entity color or avatar
+    ///
+    /// Only the random section and worker bits go into the seed, so it doesn't depend on
+    /// the timestamp. The low 80 bits are folded into 64 bits by XORing bits 64-79 into
+    /// the top 16 bits, mixed with the SplitMix64 finalizer, and the upper 32 bits of the
+    /// result are returned. This derivation is fixed, so seeds stay the same across
+    /// releases and platforms.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// let hue = sulid.color_seed() % 360;
+    /// assert_eq!(Sulid::v2_from_parts(0, 42, 7).color_seed() % 360, hue);
+    /// ```
+    pub const fn color_seed(&self) -> u32 {
+        let low = self.0 .0 & bitmask!(Self::RAND_BITS + Self::WORKER_BITS => u128);
+        let mut z = low as u64 ^ ((low >> 64) as u64) << 48;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 32) as u32
+    }
+
+    /// Guesses which version produced this sulid from its worker bits.
+    ///
+    /// SULIDs carry no version bit, and V1 and V2 share the same low 10 bits, so this
+    /// is only a heuristic for migration tooling:
+    ///
+    /// * If the upper 5 bits of the worker field are zero, the sulid is either a V1 ID
+    ///   from data center 0 or a V2 ID with a worker ID below 32, so
+    ///   [`SulidVersion::Ambiguous`] is returned.
+    /// * Otherwise the worker ID exceeds 31, which is assumed to come from a V2
+    ///   deployment, so [`SulidVersion::V2`] is returned. V1 IDs from a non-zero data
+    ///   center are therefore misreported as V2.
+    ///
+    /// [`SulidVersion::V1`] is never returned, because every V1 bit pattern is also a
+    /// valid V2 one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{Sulid, SulidVersion};
+    ///
+    /// assert_eq!(Sulid::v2_from_parts(1, 1, 1000).likely_version(), SulidVersion::V2);
+    /// assert_eq!(Sulid::v2_from_parts(1, 1, 7).likely_version(), SulidVersion::Ambiguous);
+    /// ```
+    pub const fn likely_version(&self) -> SulidVersion {
+        if self.v1_data_center_id() == 0 {
+            SulidVersion::Ambiguous
+        } else {
+            SulidVersion::V2
+        }
+    }
+
+    /// Creates a Crockford Base32 encoded string that represents this Sulid
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+    /// let sulid = Sulid::from_string(text).unwrap();
+    ///
+    /// let mut buf = [0; sulid::ULID_LEN];
+    /// let new_text = sulid.array_to_str(&mut buf);
+    ///
+    /// assert_eq!(new_text, text);
+    /// ```
+    pub fn array_to_str<'buf>(&self, buf: &'buf mut [u8; ULID_LEN]) -> &'buf mut str {
+        self.0.array_to_str(buf)
+    }
+
+    /// Wraps this Sulid for recording as a [`tracing`] field value
+    ///
+    /// The value is recorded through its [`Display`](fmt::Display) implementation, i.e. as
+    /// the canonical Crockford Base32 string, so `info!(request_id = sulid.as_tracing_value())`
+    /// is equivalent to `info!(request_id = %sulid)`. Wrapping copies the 16-byte Sulid;
+    /// the string is only formatted, into a stack buffer, when a subscriber actually
+    /// records the field, so no allocation happens on this side.
+    ///
+    /// This method requires the `tracing` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    /// tracing::info!(request_id = sulid.as_tracing_value(), "handling request");
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn as_tracing_value(&self) -> tracing::field::DisplayValue<Sulid> {
+        tracing::field::display(*self)
+    }
+
+    /// Returns this Sulid with a checksum stored in the low [`Sulid::CHECKSUM_BITS`] of
+    /// the random portion
+    ///
+    /// The checksum is the CRC-8 (polynomial `0x07`) of the big-endian bytes of all other
+    /// bits, so [`Sulid::verify_checksum`] detects any single-bit corruption and any burst
+    /// of up to 8 flipped bits. The tradeoff is entropy: the random portion of a
+    /// checksummed Sulid only carries 62 random bits, which raises the collision
+    /// probability within one millisecond and worker by a factor of 256.
+    ///
+    /// Any value previously held by the checksum bits, such as reserved bits filled by the
+    /// application, is overwritten.
+    ///
+    /// This method requires the `checksum` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42 << 8, 1).with_checksum();
+    /// assert!(sulid.verify_checksum());
+    /// let corrupted = Sulid::from_u128(sulid.u128() ^ (1 << 100));
+    /// assert!(!corrupted.verify_checksum());
+    /// ```
+    #[cfg(feature = "checksum")]
+    pub const fn with_checksum(self) -> Sulid {
+        let payload = self.0 .0 & !Self::CHECKSUM_MASK;
+        Sulid(Ulid(payload | (crc8(payload) as u128) << Self::WORKER_BITS))
+    }
+
+    /// Checks whether the checksum bits match the rest of this Sulid
+    ///
+    /// Returns `true` for Sulids produced by [`Sulid::with_checksum`] that were not
+    /// corrupted since. Sulids generated without a checksum only pass by chance, with a
+    /// probability of 1 in 256.
+    ///
+    /// This method requires the `checksum` feature.
+    #[cfg(feature = "checksum")]
+    pub const fn verify_checksum(&self) -> bool {
+        let payload = self.0 .0 & !Self::CHECKSUM_MASK;
+        (self.0 .0 & Self::CHECKSUM_MASK) >> Self::WORKER_BITS == crc8(payload) as u128
+    }
+
+    /// The bits holding the checksum of a checksummed Sulid.
+    #[cfg(feature = "checksum")]
+    const CHECKSUM_MASK: u128 = bitmask!(Self::CHECKSUM_BITS => u128) << Self::WORKER_BITS;
+
+    /// Writes the Crockford Base32 encoded string that represents this Sulid to the
+    /// start of a buffer of any length
+    ///
+    /// Only the first [`ULID_LEN`] bytes of `buf` are written; the rest is left untouched.
+    /// An [`EncodeError::BufferTooSmall`] is returned when `buf` is shorter than that.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::{EncodeError, Sulid};
+    ///
+    /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+    /// let sulid = Sulid::from_string(text).unwrap();
+    ///
+    /// let mut buf = [b' '; 64];
+    /// assert_eq!(sulid.encode_to_slice(&mut buf[10..]).unwrap(), text);
+    /// assert_eq!(&buf[10..36], text.as_bytes());
+    ///
+    /// assert_eq!(sulid.encode_to_slice(&mut [0; 25]), Err(EncodeError::BufferTooSmall));
+    /// ```
+    pub fn encode_to_slice<'buf>(&self, buf: &'buf mut [u8]) -> Result<&'buf mut str, EncodeError> {
+        match buf.get_mut(..ULID_LEN) {
+            Some(buf) => Ok(self.array_to_str(buf.try_into().unwrap())),
+            None => Err(EncodeError::BufferTooSmall),
+        }
+    }
+
+    /// Test if the Sulid is nil
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::from_u128(1);
+    /// assert!(!sulid.is_nil());
+    ///
+    /// let nil = Sulid::nil();
+    /// assert!(nil.is_nil());
+    /// ```
+    #[inline]
+    pub const fn is_nil(&self) -> bool {
+        self.0.is_nil()
+    }
+
+    /// Increment the random number, make sure that the ts millis stays the same
+    pub const fn increment(&self) -> Option<Sulid> {
+        const MAX_RANDOM: u128 = bitmask!(Sulid::RAND_BITS => u128);
+
+        if ((self.0 .0 >> (Sulid::DATA_CENTER_BITS + Sulid::MACHINE_BITS)) & MAX_RANDOM)
+            == MAX_RANDOM
+        {
+            None
+        } else {
+            Some(Sulid(Ulid(
+                self.0 .0 + (1 << (Sulid::DATA_CENTER_BITS + Sulid::MACHINE_BITS)),
+            )))
+        }
+    }
+
+    /// Returns an anonymized copy of this sulid that keeps only the timestamp
+    ///
+    /// The random section and worker bits are zeroed, so the result neither leaks entropy
+    /// nor the originating worker, while still sorting by time. Sulids of the same
+    /// millisecond all anonymize to the same value; use [`Sulid::anonymize_with_sequence`]
+    /// to keep them distinct.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// assert_eq!(sulid.anonymize(), Sulid::v2_from_parts(1_700_000_000_000, 0, 0));
+    /// ```
+    pub const fn anonymize(&self) -> Sulid {
+        self.anonymize_with_sequence(0)
+    }
+
+    /// Returns an anonymized copy of this sulid with a per-millisecond sequence number
+    ///
+    /// Like [`Sulid::anonymize`], but `sequence` is stored in the random section. Numbering
+    /// the Sulids of each millisecond in their original order, e.g. 0, 1, 2, ..., keeps the
+    /// anonymized values unique and in the original order without revealing the original
+    /// random bits. The worker bits are still zeroed.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let first = Sulid::v2_from_parts(1_700_000_000_000, 900, 7);
+    /// let second = Sulid::v2_from_parts(1_700_000_000_000, 100, 3);
+    /// assert!(first.anonymize_with_sequence(0) < second.anonymize_with_sequence(1));
+    /// ```
+    pub const fn anonymize_with_sequence(&self, sequence: u128) -> Sulid {
+        const SHIFT: u8 = Sulid::RAND_BITS + Sulid::WORKER_BITS;
+        Sulid(Ulid(
+            (self.0 .0 & !bitmask!(SHIFT => u128))
+                | (sequence & bitmask!(Sulid::RAND_BITS => u128)) << Self::WORKER_BITS,
+        ))
+    }
+
+    /// Checks whether this sulid could have been produced by monotonic generation right after `prev`
+    ///
+    /// This is the case if both have the same timestamp and worker bits and this sulid's
+    /// random section is greater, as produced by [`Sulid::increment`] or a monotonic
+    /// generator within one millisecond. Pairs from different milliseconds return `false`,
+    /// since any later Sulid is consistent with monotonic generation there. This helps
+    /// auditing whether a stream was generated in monotonic mode.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let prev = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// assert!(prev.increment().unwrap().is_plausible_increment_of(&prev));
+    /// assert!(!prev.is_plausible_increment_of(&prev));
+    /// ```
+    pub const fn is_plausible_increment_of(&self, prev: &Sulid) -> bool {
+        self.timestamp_ms() == prev.timestamp_ms()
+            && self.v2_worker_id() == prev.v2_worker_id()
+            && self.random() > prev.random()
+    }
+
+    /// Describes how `other` differs from this sulid, field by field
+    ///
+    /// Each delta is `other`'s field minus this sulid's, so all are zero for equal Sulids.
+    /// This explains why two IDs differ more readably than comparing their raw `u128`s.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let a = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// let b = Sulid::v2_from_parts(1_700_000_000_005, 40, 9);
+    /// let diff = a.diff(&b);
+    /// assert_eq!(diff.timestamp_delta_ms, 5);
+    /// assert_eq!(diff.random_delta, -2);
+    /// assert_eq!(diff.worker_delta, 2);
+    /// ```
+    pub const fn diff(&self, other: &Sulid) -> SulidDiff {
+        SulidDiff {
+            timestamp_delta_ms: other.timestamp_ms() as i64 - self.timestamp_ms() as i64,
+            random_delta: other.random() as i128 - self.random() as i128,
+            worker_delta: other.worker_field() as i16 - self.worker_field() as i16,
+        }
+    }
+
+    /// Returns the index of the most significant bit in which this sulid and `other`
+    /// differ, or `None` if they are equal
+    ///
+    /// Bit 0 is the least significant bit of the worker field and bit 127 the most
+    /// significant timestamp bit. This is the branching operation of a crit-bit tree keyed
+    /// on Sulids.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::from_u128(0b1010);
+    /// assert_eq!(sulid.critical_bit(&Sulid::from_u128(0b0110)), Some(3));
+    /// assert_eq!(sulid.critical_bit(&sulid), None);
+    /// ```
+    pub const fn critical_bit(&self, other: &Sulid) -> Option<u8> {
+        match self.0 .0 ^ other.0 .0 {
+            0 => None,
+            diff => Some((u128::BITS - 1 - diff.leading_zeros()) as u8),
+        }
+    }
+
+    /// Returns the Sulid that immediately follows this one in lexicographic order.
+    ///
+    /// Unlike [`Sulid::increment`], this adds one to the whole 128-bit value, so the
+    /// timestamp and worker fields may change. Returns `None` on overflow.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// assert_eq!(Sulid::nil().next(), Some(Sulid::from_u128(1)));
+    /// assert_eq!(Sulid::from_u128(u128::MAX).next(), None);
+    /// ```
+    pub const fn next(&self) -> Option<Sulid> {
+        match self.0 .0.checked_add(1) {
+            Some(u) => Some(Sulid(Ulid(u))),
+            None => None,
+        }
+    }
+
+    /// Returns the Sulid that immediately precedes this one in lexicographic order.
+    ///
+    /// This subtracts one from the whole 128-bit value. Returns `None` for the nil Sulid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// assert_eq!(Sulid::from_u128(1).prev(), Some(Sulid::nil()));
+    /// assert_eq!(Sulid::nil().prev(), None);
+    /// ```
+    pub const fn prev(&self) -> Option<Sulid> {
+        match self.0 .0.checked_sub(1) {
+            Some(u) => Some(Sulid(Ulid(u))),
+            None => None,
+        }
+    }
+
+    /// Creates a Sulid using the provided bytes array.
+    ///
+    /// # Example
+    /// ```
+    /// use sulid::Sulid;
+    /// let bytes = [0xFF; 16];
+    ///
+    /// let sulid = Sulid::from_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     sulid.to_string(),
+    ///     "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Sulid {
+        Self(Ulid::from_bytes(bytes))
+    }
+
+    /// Decodes a Sulid from a protobuf `bytes` field encoded by [`Sulid::to_proto_bytes`]
+    ///
+    /// A [`DecodeError::InvalidLength`] is returned when the field isn't exactly 16
+    /// bytes long.
+    pub fn from_proto_bytes(bytes: &[u8]) -> Result<Sulid, DecodeError> {
+        bytes
+            .try_into()
+            .map(Sulid::from_bytes)
+            .map_err(|_| DecodeError::InvalidLength)
+    }
+
+    /// Creates a Sulid from a base64url string created by [`Sulid::to_base64url`]
+    ///
+    /// A [`DecodeError::InvalidLength`] is returned when the string isn't exactly 22
+    /// characters long, and a [`DecodeError::InvalidChar`] when it contains characters
+    /// outside the base64url alphabet (including padding) or its last character has any
+    /// of the 4 padding bits set.
+    pub fn from_base64url(encoded: &str) -> Result<Sulid, DecodeError> {
+        if encoded.len() != BASE64URL_LEN {
+            return Err(DecodeError::InvalidLength);
+        }
+        let mut value = 0u128;
+        for (i, byte) in encoded.bytes().enumerate() {
+            let digit = match byte {
+                b'A'..=b'Z' => byte - b'A',
+                b'a'..=b'z' => byte - b'a' + 26,
+                b'0'..=b'9' => byte - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => return Err(DecodeError::InvalidChar),
+            };
+            if i + 1 < BASE64URL_LEN {
+                value = (value << 6) | u128::from(digit);
+            } else if digit & 0xF == 0 {
+                value = (value << 2) | u128::from(digit >> 4);
+            } else {
+                return Err(DecodeError::InvalidChar);
+            }
+        }
+        Ok(Sulid::from_u128(value))
+    }
+
+    /// Returns the bytes of the Sulid in big-endian order.
+    ///
+    /// # Example
+    /// ```
+    /// use sulid::Sulid;
+    ///
+    /// let text = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
+    /// let sulid = Sulid::from_string(text).unwrap();
+    ///
+    /// assert_eq!(sulid.to_bytes(), [0xFF; 16]);
+    /// ```
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.0.to_bytes()
+    }
+
+    /// Returns the bytes of a time-ordered UUID (UUIDv7 layout) derived from this Sulid.
+    ///
+    /// Wide-column stores such as ScyllaDB and Cassandra compare non-v1 UUID clustering
+    /// keys byte-wise. For this layout that agrees with time order, because the timestamp
+    /// occupies the most significant bytes:
+    ///
+    /// ```text
+    /// | 48-bit Timestamp | 4-bit Version (7) | 12 bits | 2-bit Variant (0b10) | 62 bits |
+    /// ```
+    ///
+    /// The 12 + 62 = 74 trailing bits hold the low 64 bits of the random section followed
+    /// by the 10-bit worker ID, in that order. The version and variant bits replace the
+    /// top 6 bits of the random section, so the conversion is one-way: SULIDs from
+    /// different milliseconds compare the same as the resulting bytes, while SULIDs from
+    /// the same millisecond may not.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let bytes = Sulid::v2_from_parts(0x0102_0304_0506, 0, 1).to_timeuuid_like_bytes();
+    /// assert_eq!(bytes[..6], [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(bytes[6] >> 4, 7);
+    /// assert_eq!(bytes[8] >> 6, 0b10);
+    /// ```
+    pub const fn to_timeuuid_like_bytes(&self) -> [u8; 16] {
+        const TAIL_BITS: u8 = 74;
+        const LOW_BITS: u8 = 62;
+        let tail = self.0 .0 & bitmask!(TAIL_BITS => u128);
+        let uuid = ((self.timestamp_ms() as u128) << 80)
+            | (0x7 << 76)
+            | ((tail >> LOW_BITS) << 64)
+            | (0b10 << 62)
+            | (tail & bitmask!(LOW_BITS => u128));
+        uuid.to_be_bytes()
+    }
+
+    /// Maps this sulid into the 12-byte shape of a MongoDB ObjectId
+    ///
+    /// The first 4 bytes are the timestamp in whole seconds, big-endian, like an
+    /// ObjectId's, so the result sorts by time alongside real ObjectIds. The remaining 8
+    /// bytes hold the millisecond within the second (10 bits), the top 44 bits of the
+    /// random section and the worker bits (10 bits), in this order. As a result, the
+    /// byte order of the mapped values never contradicts the order of the Sulids.
+    ///
+    /// The mapping is lossy, as a Sulid has more bits than an ObjectId: the low 26 random
+    /// bits are dropped, so distinct Sulids of the same millisecond and worker may map to
+    /// the same bytes. The seconds wrap around after 2106-02-07, like ObjectId timestamps
+    /// do. The last 8 bytes also don't follow the ObjectId split into a 5-byte random value
+    /// and a 3-byte counter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let earlier = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+    /// let later = Sulid::v2_from_parts(1_700_000_000_001, 0, 0);
+    /// assert!(earlier.to_objectid_like() < later.to_objectid_like());
+    /// assert_eq!(earlier.to_objectid_like()[..4], 1_700_000_000u32.to_be_bytes());
+    /// ```
+    pub const fn to_objectid_like(&self) -> [u8; 12] {
+        let timestamp_ms = self.timestamp_ms();
+        let seconds = ((timestamp_ms / 1000) as u32).to_be_bytes();
+        let rest = ((timestamp_ms % 1000) << 54
+            | ((self.random() >> (Self::RAND_BITS - 44)) as u64) << Self::WORKER_BITS
+            | self.v2_worker_id() as u64)
+            .to_be_bytes();
+        [
+            seconds[0], seconds[1], seconds[2], seconds[3], rest[0], rest[1], rest[2], rest[3],
+            rest[4], rest[5], rest[6], rest[7],
+        ]
+    }
+
+    /// Reinterprets this sulid as a canonical [`Ulid`]
+    ///
+    /// This is a reinterpretation, not a data change: the 128 bits are kept as they are,
+    /// and the ULID simply reads the random section and the worker bits together as its
+    /// 80-bit random field. The timestamp, the string form and the sort order are the
+    /// same, so the result can be stored next to ULIDs in the same column.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::example();
+    /// let ulid = sulid.to_canonical_ulid();
+    /// assert_eq!(ulid.to_string(), sulid.to_string());
+    /// assert_eq!(ulid.timestamp_ms(), sulid.timestamp_ms());
+    /// ```
+    pub const fn to_canonical_ulid(&self) -> Ulid {
+        self.0
+    }
+}
+
+/// The version of a Sulid layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SulidVersion {
+    /// A 5-bit data center ID followed by a 5-bit machine ID.
+    V1,
+    /// A 10-bit worker ID.
+    V2,
+    /// The version cannot be determined.
+    Ambiguous,
+}
+
+/// The granularity of a Sulid timestamp.
+///
+/// Sulids store a 48-bit count of these units since the Unix epoch. Coarser units stretch
+/// the lifespan of the timestamp, e.g. to almost 9 million years with seconds, at the cost
+/// of ordering within a unit being random. The unit is not encoded in the Sulid: readers
+/// must know it, and Sulids with different units are not comparable with each other.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// Milliseconds, the standard Sulid and ULID granularity.
+    #[default]
+    Millis,
+    /// Hundredths of a second, i.e. 10 milliseconds.
+    CentiMillis,
+    /// Seconds.
+    Seconds,
+}
+
+impl TimeUnit {
+    /// Returns the length of one unit in milliseconds.
+    pub const fn as_millis(self) -> u64 {
+        match self {
+            TimeUnit::Millis => 1,
+            TimeUnit::CentiMillis => 10,
+            TimeUnit::Seconds => 1_000,
+        }
+    }
+}
+
+/// The field-by-field difference between two Sulids, as returned by [`Sulid::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SulidDiff {
+    /// The difference of the timestamps in milliseconds.
+    pub timestamp_delta_ms: i64,
+    /// The difference of the random sections.
+    pub random_delta: i128,
+    /// The difference of the 10-bit worker fields.
+    pub worker_delta: i16,
+}
+
+impl SulidDiff {
+    /// Returns `true` if the Sulids are equal.
+    pub const fn is_zero(&self) -> bool {
+        self.timestamp_delta_ms == 0 && self.random_delta == 0 && self.worker_delta == 0
+    }
+}
+
+/// A timestamp in milliseconds since the Unix epoch for comparing Sulids by time only
+///
+/// Comparing the keys returned by [`Sulid::time_key`] ignores the random and worker bits,
+/// so all Sulids of one millisecond are equal. This expresses time-range checks without
+/// constructing boundary Sulids.
+///
+/// # Example
+/// ```rust
+/// use sulid::{Sulid, TimeKey};
+///
+/// let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+/// assert!(sulid.time_key() < TimeKey(1_700_000_000_001));
+/// assert!(sulid.time_key() >= TimeKey(1_700_000_000_000));
+/// assert_eq!(sulid.time_key(), TimeKey(1_700_000_000_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeKey(pub u64);
+
+/// An error indicating that a Sulid part does not fit into its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdRangeError {
+    /// The timestamp exceeds 48 bits.
+    Timestamp,
+    /// The random number exceeds 70 bits.
+    Random,
+    /// The data center ID exceeds 5 bits.
+    DataCenterId,
+    /// The machine ID exceeds 5 bits.
+    MachineId,
+    /// The worker ID exceeds 10 bits.
+    WorkerId,
+    /// The date is not a valid calendar date on or after 1970-01-01.
+    Date,
+}
+
+impl IdRangeError {
+    const fn message(&self) -> &'static str {
+        match self {
+            IdRangeError::Timestamp => "timestamp_ms must be in the range 0-281474976710655",
+            IdRangeError::Random => "random must be in the range 0-1180591620717411303423",
+            IdRangeError::DataCenterId => "data_center_id must be in the range 0-31",
+            IdRangeError::MachineId => "machine_id must be in the range 0-31",
+            IdRangeError::WorkerId => "worker_id must be in the range 0-1023",
+            IdRangeError::Date => "date must be a valid calendar date on or after 1970-01-01",
+        }
+    }
+}
+
+impl fmt::Display for IdRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// An error that can occur when strictly decoding a Crockford Base32 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrictDecodeError {
+    /// The length of the string does not match the expected length
+    InvalidLength,
+    /// A non-base32 character was found
+    InvalidChar,
+    /// The first character is above `7`, so the value exceeds 128 bits
+    Overflow,
+}
+
+impl From<DecodeError> for StrictDecodeError {
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::InvalidLength => StrictDecodeError::InvalidLength,
+            DecodeError::InvalidChar => StrictDecodeError::InvalidChar,
+        }
+    }
+}
+
+impl fmt::Display for StrictDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StrictDecodeError::InvalidLength => "invalid length",
+            StrictDecodeError::InvalidChar => "invalid character",
+            StrictDecodeError::Overflow => "value exceeds 128 bits",
+        })
+    }
+}
+
+/// A decode error with details about where decoding failed.
+///
+/// It can be converted into the less detailed [`DecodeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SulidDecodeError {
+    /// The length of the string does not match the expected length
+    InvalidLength {
+        /// The expected number of characters
+        expected: usize,
+        /// The actual number of characters
+        actual: usize,
+    },
+    /// A non-base32 character was found
+    InvalidChar {
+        /// The zero-based character index of the first invalid character
+        position: usize,
+        /// The invalid character
+        character: char,
+    },
+}
+
+impl SulidDecodeError {
+    /// Returns the position of the invalid character, if any.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            SulidDecodeError::InvalidLength { .. } => None,
+            SulidDecodeError::InvalidChar { position, .. } => Some(*position),
+        }
+    }
+}
+
+impl From<SulidDecodeError> for DecodeError {
+    fn from(err: SulidDecodeError) -> Self {
+        match err {
+            SulidDecodeError::InvalidLength { .. } => DecodeError::InvalidLength,
+            SulidDecodeError::InvalidChar { .. } => DecodeError::InvalidChar,
+        }
+    }
+}
+
+impl fmt::Display for SulidDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SulidDecodeError::InvalidLength { expected, actual } => write!(
+                f,
+                "invalid length: expected {} characters, found {}",
+                expected, actual
+            ),
+            SulidDecodeError::InvalidChar {
+                position,
+                character,
+            } => write!(
+                f,
+                "invalid character {:?} at position {}",
+                character, position
+            ),
+        }
+    }
+}
+
+/// An error that can occur when assembling a Sulid from its component strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The timestamp is not a decimal or `0x`-prefixed hexadecimal integer.
+    InvalidTimestamp,
+    /// The random number is not a decimal or `0x`-prefixed hexadecimal integer.
+    InvalidRandom,
+    /// The data center ID is not a decimal or `0x`-prefixed hexadecimal integer.
+    InvalidDataCenterId,
+    /// The machine ID is not a decimal or `0x`-prefixed hexadecimal integer.
+    InvalidMachineId,
+    /// A component is a valid integer but does not fit into its field.
+    OutOfRange(IdRangeError),
+}
+
+impl From<IdRangeError> for ParseError {
+    fn from(err: IdRangeError) -> Self {
+        ParseError::OutOfRange(err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = match self {
+            ParseError::InvalidTimestamp => "timestamp_ms",
+            ParseError::InvalidRandom => "random",
+            ParseError::InvalidDataCenterId => "data_center_id",
+            ParseError::InvalidMachineId => "machine_id",
+            ParseError::OutOfRange(err) => return err.fmt(f),
+        };
+        write!(
+            f,
+            "{field} must be a decimal or 0x-prefixed hexadecimal integer"
+        )
+    }
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal integer without sign or whitespace.
+fn parse_component(s: &str) -> Option<u128> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    // All digits are valid, so parsing can only fail by overflow.
+    Some(u128::from_str_radix(digits, radix).unwrap_or(u128::MAX))
+}
+
+/// Iterates over every Sulid from `start` (inclusive) to `end` (exclusive).
+///
+/// The iterator increments the full 128-bit value, so it visits every random value and
+/// worker ID in between, not just the Sulids a single worker could produce. It is lazy,
+/// but a range spanning even one millisecond contains 2^80 Sulids, so only small, dense
+/// ranges can be exhausted. The iterator is empty if `start >= end`.
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// let start = Sulid::v2_from_parts(1, 0, 1022);
+/// let ids: Vec<_> = sulid::range(start, Sulid::v2_from_parts(1, 1, 1)).collect();
+/// assert_eq!(ids.len(), 3);
+/// assert_eq!(ids[2], Sulid::v2_from_parts(1, 1, 0));
+/// ```
+pub fn range(start: Sulid, end: Sulid) -> impl Iterator<Item = Sulid> {
+    (start.u128()..end.u128()).map(Sulid::from_u128)
+}
+
+/// Computes the CRC-8 (polynomial `0x07`, zero initial value) of the big-endian bytes of `value`.
+#[cfg(feature = "checksum")]
+const fn crc8(value: u128) -> u8 {
+    let bytes = value.to_be_bytes();
+    let mut crc = 0u8;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// Splits a V2 worker ID into its V1 `(data_center_id, machine_id)` pair.
+///
+/// This is the canonical V1↔V2 mapping: the high 5 bits of the worker ID are the
+/// data center ID and the low 5 bits are the machine ID, so a V1 Sulid and a V2 Sulid
+/// built from corresponding IDs share the same low 10 bits.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(sulid::split_worker_id(0b00011_00101), (3, 5));
+/// ```
+pub const fn split_worker_id(worker_id: u16) -> (u8, u8) {
+    (
+        ((worker_id >> Sulid::MACHINE_BITS) & bitmask!(Sulid::DATA_CENTER_BITS => u16)) as u8,
+        (worker_id & bitmask!(Sulid::MACHINE_BITS => u16)) as u8,
+    )
+}
+
+/// Combines a V1 `(data_center_id, machine_id)` pair into a V2 worker ID.
+///
+/// This is the inverse of [`split_worker_id`].
+///
+/// NOTE: Any overflow bits in the given args are discarded
+///
+/// # Example
+/// ```rust
+/// assert_eq!(sulid::combine_worker_id(3, 5), 0b00011_00101);
+/// ```
+pub const fn combine_worker_id(data_center_id: u8, machine_id: u8) -> u16 {
+    (((data_center_id & bitmask!(Sulid::DATA_CENTER_BITS => u8)) as u16) << Sulid::MACHINE_BITS)
+        | (machine_id & bitmask!(Sulid::MACHINE_BITS => u8)) as u16
+}
+
+impl Default for Sulid {
+    fn default() -> Self {
+        Sulid::nil()
+    }
+}
+
+impl From<(u64, u128, u8, u8)> for Sulid {
+    /// NOTE: It is only meaningful for v1.
+    fn from((timestamp_ms, random, data_center_id, machine_id): (u64, u128, u8, u8)) -> Self {
+        Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id)
     }
 }
 
@@ -425,39 +1981,663 @@ impl From<Sulid> for [u8; 16] {
     }
 }
 
-impl FromStr for Sulid {
-    type Err = DecodeError;
+impl FromStr for Sulid {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Sulid::from_string(s)
+    }
+}
+
+impl TryFrom<&'_ str> for Sulid {
+    type Error = DecodeError;
+
+    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
+        Sulid::from_string(value)
+    }
+}
+
+/// Overwrites the Sulid with zeros, turning it into the nil Sulid.
+///
+/// This requires the `zeroize` feature.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Sulid {
+    fn zeroize(&mut self) {
+        self.0 .0.zeroize();
+    }
+}
+
+impl fmt::Display for Sulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let mut buffer = [0; ULID_LEN];
+        write!(f, "{}", self.array_to_str(&mut buffer))
+    }
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static() {
+        let mut s = [0u8; ULID_LEN];
+        let s = Sulid::from_u128(0x41414141414141414141414141414141).array_to_str(&mut s);
+        let u = Sulid::from_string(s).unwrap();
+        assert_eq!(s, "21850M2GA1850M2GA1850M2GA1");
+        assert_eq!(u.u128(), 0x41414141414141414141414141414141);
+    }
+
+    /// Asserts that every conversion of `sulid` converts back to the same value.
+    fn assert_round_trips(sulid: Sulid) {
+        let value = sulid.u128();
+        assert_eq!(Sulid::from_u128(value), sulid);
+        assert_eq!(u128::from(sulid), value);
+        assert_eq!(Sulid::from(value), sulid);
+
+        assert_eq!(Sulid::from_bytes(sulid.to_bytes()), sulid);
+        assert_eq!(Sulid::from(<[u8; 16]>::from(sulid)), sulid);
+        assert_eq!(sulid.to_bytes(), value.to_be_bytes());
+
+        let mut buf = [0u8; ULID_LEN];
+        let text = sulid.array_to_str(&mut buf);
+        assert_eq!(Sulid::from_string(text), Ok(sulid));
+        assert_eq!(Sulid::from_string_strict(text), Ok(sulid));
+        assert_eq!(Sulid::from_string_verbose(text), Ok(sulid));
+        assert_eq!(text.parse::<Sulid>(), Ok(sulid));
+        assert_eq!(Sulid::try_from(&*text), Ok(sulid));
+        let mut lowercase = buf;
+        lowercase.make_ascii_lowercase();
+        let lowercase = core::str::from_utf8(&lowercase).unwrap();
+        assert_eq!(Sulid::from_string(lowercase), Ok(sulid));
+
+        let (timestamp_ms, random, data_center_id, machine_id) = sulid.into();
+        assert_eq!(
+            Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id),
+            sulid
+        );
+        assert_eq!(
+            Sulid::from((timestamp_ms, random, data_center_id, machine_id)),
+            sulid
+        );
+        let (timestamp_ms, random, worker_id) = sulid.into();
+        assert_eq!(Sulid::v2_from_parts(timestamp_ms, random, worker_id), sulid);
+        assert_eq!(Sulid::from((timestamp_ms, random, worker_id)), sulid);
+        assert_eq!(
+            worker_id,
+            combine_worker_id(data_center_id, machine_id),
+            "worker ID of {sulid:?}"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = Sulid::v1_from_parts(1_000, 500, 3, 4);
+        let b = Sulid::v1_from_parts(750, 1_500, 3, 9);
+        let diff = a.diff(&b);
+        assert_eq!(diff.timestamp_delta_ms, -250);
+        assert_eq!(diff.random_delta, 1_000);
+        assert_eq!(diff.worker_delta, 5);
+        assert!(!diff.is_zero());
+        assert_eq!(b.diff(&a).random_delta, -1_000);
+        assert!(a.diff(&a).is_zero());
+
+        let max = Sulid::from_u128(u128::MAX);
+        let diff = Sulid::nil().diff(&max);
+        assert_eq!(diff.timestamp_delta_ms, bitmask!(Sulid::TIME_BITS => i64));
+        assert_eq!(diff.random_delta, bitmask!(Sulid::RAND_BITS => i128));
+        assert_eq!(diff.worker_delta, 1023);
+    }
+
+    #[test]
+    fn test_critical_bit() {
+        let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 6);
+        assert_eq!(sulid.critical_bit(&sulid), None);
+        assert_eq!(
+            sulid.critical_bit(&Sulid::v2_from_parts(1_700_000_000_000, 42, 7)),
+            Some(0)
+        );
+        assert_eq!(Sulid::MIN.critical_bit(&Sulid::MAX), Some(127));
+
+        // Timestamps differing in their lowest bit dominate any random or worker difference.
+        let later = Sulid::v2_from_parts(1_700_000_000_001, 0, 0);
+        let bit = Sulid::RAND_BITS + Sulid::WORKER_BITS;
+        assert_eq!(sulid.critical_bit(&later), Some(bit));
+        assert_eq!(later.critical_bit(&sulid), Some(bit));
+    }
+
+    #[test]
+    fn test_round_trip_invariants() {
+        assert_round_trips(Sulid::nil());
+        assert_round_trips(Sulid::MIN);
+        assert_round_trips(Sulid::MAX);
+        // Single bits and low masks cover every bit on both sides of each field boundary.
+        for bit in 0..128 {
+            assert_round_trips(Sulid::from_u128(1 << bit));
+            assert_round_trips(Sulid::from_u128(bitmask!(bit => u128)));
+            assert_round_trips(Sulid::from_u128(!bitmask!(bit => u128)));
+        }
+
+        // splitmix64, so the test needs no RNG and runs without the `std` feature.
+        let mut state = 0x5EED_u64;
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        for _ in 0..10_000 {
+            let value = (next() as u128) << 64 | next() as u128;
+            assert_round_trips(Sulid::from_u128(value));
+        }
+    }
+
+    #[test]
+    fn test_encode_to_slice() {
+        let sulid = Sulid::from_u128(0x41414141414141414141414141414141);
+        let expected = "21850M2GA1850M2GA1850M2GA1";
+
+        let mut exact = [0u8; ULID_LEN];
+        assert_eq!(sulid.encode_to_slice(&mut exact).map(|s| &*s), Ok(expected));
+        assert_eq!(&exact, expected.as_bytes());
+
+        let mut larger = [b'#'; ULID_LEN + 1];
+        assert_eq!(
+            sulid.encode_to_slice(&mut larger).map(|s| &*s),
+            Ok(expected)
+        );
+        assert_eq!(&larger[..ULID_LEN], expected.as_bytes());
+        assert_eq!(larger[ULID_LEN], b'#');
+
+        let mut smaller = [b'#'; ULID_LEN - 1];
+        assert_eq!(
+            sulid.encode_to_slice(&mut smaller),
+            Err(EncodeError::BufferTooSmall)
+        );
+        assert_eq!(smaller, [b'#'; ULID_LEN - 1]);
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    fn test_as_tracing_value() {
+        use std::string::String;
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records the values of the fields named `request_id`.
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                if field.name() == "request_id" {
+                    self.0.lock().unwrap().push(format!("{:?}", value));
+                }
+            }
+        }
+
+        struct TestSubscriber(Arc<Mutex<Vec<String>>>);
+
+        impl Subscriber for TestSubscriber {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut Recorder(Arc::clone(&self.0)));
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut Recorder(Arc::clone(&self.0)));
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = TestSubscriber(Arc::clone(&recorded));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request", request_id = sulid.as_tracing_value());
+            let _guard = span.enter();
+        });
+
+        assert_eq!(*recorded.lock().unwrap(), ["01D39ZY06FGSCTVN4T2V9PKHFZ"]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut sulid = Sulid::v1_from_parts(1_700_000_000_000, 42, 3, 4);
+        assert!(!sulid.is_nil());
+        sulid.zeroize();
+        assert!(sulid.is_nil());
+    }
+
+    #[test]
+    fn test_to_canonical_ulid() {
+        let sulid = Sulid::example();
+        let ulid = sulid.to_canonical_ulid();
+        assert_eq!(ulid.0, sulid.u128());
+        assert_eq!(ulid.timestamp_ms(), sulid.timestamp_ms());
+        assert_eq!(
+            ulid.random(),
+            sulid.random() << Sulid::WORKER_BITS | u128::from(sulid.v2_worker_id())
+        );
+
+        let mut sulid_buf = [0; ULID_LEN];
+        let mut ulid_buf = [0; ULID_LEN];
+        assert_eq!(
+            ulid.array_to_str(&mut ulid_buf),
+            sulid.array_to_str(&mut sulid_buf)
+        );
+        assert_eq!(Ulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"), Ok(ulid));
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        assert_eq!(Sulid::from_decimal("0"), Ok(Sulid::nil()));
+        assert_eq!(Sulid::from_decimal("000042"), Ok(Sulid::from_u128(42)));
+        assert_eq!(
+            Sulid::from_decimal("340282366920938463463374607431768211455"),
+            Ok(Sulid::MAX)
+        );
+
+        assert_eq!(Sulid::from_decimal(""), Err(DecodeError::InvalidLength));
+        assert_eq!(
+            Sulid::from_decimal("340282366920938463463374607431768211456"),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(Sulid::from_decimal("+1"), Err(DecodeError::InvalidChar));
+        assert_eq!(Sulid::from_decimal("1 "), Err(DecodeError::InvalidChar));
+    }
+
+    #[test]
+    fn test_time_key() {
+        const BOUNDARY: u64 = 1_700_000_000_000;
+        let before = Sulid::v2_from_parts(BOUNDARY - 1, bitmask!(Sulid::RAND_BITS => u128), 1023);
+        let at_min = Sulid::v2_from_parts(BOUNDARY, 0, 0);
+        let at_max = Sulid::v2_from_parts(BOUNDARY, bitmask!(Sulid::RAND_BITS => u128), 1023);
+        let after = Sulid::v2_from_parts(BOUNDARY + 1, 0, 0);
+        let key = TimeKey(BOUNDARY);
+
+        assert!(before.time_key() < key);
+        assert_eq!(at_min.time_key(), key);
+        assert_eq!(at_max.time_key(), key);
+        assert!(after.time_key() > key);
+        assert_ne!(at_min, at_max);
+    }
+
+    #[test]
+    fn test_timestamp_bytes() {
+        for timestamp_ms in [0, 1, 1_700_000_000_000, bitmask!(Sulid::TIME_BITS => u64)] {
+            let sulid = Sulid::v1_from_parts(timestamp_ms, 42, 3, 4);
+            let bytes = sulid.timestamp_bytes();
+            assert_eq!(bytes, sulid.to_bytes()[..6]);
+
+            let moved = Sulid::v1_from_parts(5, 42, 3, 4).with_timestamp_bytes(bytes);
+            assert_eq!(moved, sulid);
+            assert_eq!(moved.timestamp_ms(), timestamp_ms);
+        }
+    }
+
+    #[test]
+    fn test_objectid_like() {
+        let ids = [
+            Sulid::v2_from_parts(1_700_000_000_000, bitmask!(Sulid::RAND_BITS => u128), 1023),
+            Sulid::v2_from_parts(1_700_000_000_001, 0, 0),
+            Sulid::v2_from_parts(1_700_000_000_999, 5 << 26, 0),
+            Sulid::v2_from_parts(1_700_000_000_999, 5 << 26, 1),
+            Sulid::v2_from_parts(1_700_000_000_999, 6 << 26, 0),
+            Sulid::v2_from_parts(1_700_000_001_000, 0, 0),
+            Sulid::v2_from_parts(1_800_000_000_000, 0, 0),
+        ];
+        let mapped = ids.map(|id| id.to_objectid_like());
+        assert!(mapped.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let bytes = Sulid::v2_from_parts(1_700_000_000_999, 5 << 26, 7).to_objectid_like();
+        assert_eq!(bytes[..4], 1_700_000_000u32.to_be_bytes());
+        assert_eq!(
+            u64::from_be_bytes(bytes[4..].try_into().unwrap()),
+            999 << 54 | 5 << 10 | 7
+        );
+        // The low 26 random bits are dropped.
+        assert_eq!(
+            Sulid::v2_from_parts(1_700_000_000_999, (5 << 26) | 1, 7).to_objectid_like(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_is_plausible_increment_of() {
+        let prev = Sulid::v1_from_parts(1_700_000_000_000, 42, 3, 4);
+        assert!(prev.increment().unwrap().is_plausible_increment_of(&prev));
+        assert!(
+            Sulid::v1_from_parts(1_700_000_000_000, 1000, 3, 4).is_plausible_increment_of(&prev)
+        );
+
+        // Not monotonic within the millisecond.
+        assert!(!prev.is_plausible_increment_of(&prev));
+        assert!(!Sulid::v1_from_parts(1_700_000_000_000, 41, 3, 4).is_plausible_increment_of(&prev));
+        // Different timestamp or worker.
+        assert!(!Sulid::v1_from_parts(1_700_000_000_001, 43, 3, 4).is_plausible_increment_of(&prev));
+        assert!(!Sulid::v1_from_parts(1_700_000_000_000, 43, 3, 5).is_plausible_increment_of(&prev));
+    }
+
+    #[test]
+    fn test_from_component_strings() {
+        assert_eq!(
+            Sulid::from_component_strings("1700000000000", "42", "3", "4"),
+            Ok(Sulid::v1_from_parts(1_700_000_000_000, 42, 3, 4))
+        );
+        assert_eq!(
+            Sulid::from_component_strings("0x18BCFE56800", "0X3FFFFFFFFFFFFFFFFF", "0x1f", "0"),
+            Ok(Sulid::v1_from_parts(
+                1_700_000_000_000,
+                bitmask!(Sulid::RAND_BITS => u128),
+                31,
+                0
+            ))
+        );
+
+        let parse =
+            |ts, random, dc, machine| Sulid::from_component_strings(ts, random, dc, machine);
+        assert_eq!(parse("", "1", "1", "1"), Err(ParseError::InvalidTimestamp));
+        assert_eq!(parse("1", "0x", "1", "1"), Err(ParseError::InvalidRandom));
+        assert_eq!(
+            parse("1", "1", "+1", "1"),
+            Err(ParseError::InvalidDataCenterId)
+        );
+        assert_eq!(
+            parse("1", "1", "1", " 1"),
+            Err(ParseError::InvalidMachineId)
+        );
+        assert_eq!(parse("1", "0xg", "x", "1"), Err(ParseError::InvalidRandom));
+
+        let out_of_range = |err| Err(ParseError::OutOfRange(err));
+        assert_eq!(
+            parse("281474976710656", "1", "1", "1"),
+            out_of_range(IdRangeError::Timestamp)
+        );
+        assert_eq!(
+            parse("99999999999999999999999999999999999999999", "1", "1", "1"),
+            out_of_range(IdRangeError::Timestamp)
+        );
+        assert_eq!(
+            parse("1", "0x400000000000000000", "1", "1"),
+            out_of_range(IdRangeError::Random)
+        );
+        assert_eq!(
+            parse("1", "1", "256", "1"),
+            out_of_range(IdRangeError::DataCenterId)
+        );
+        assert_eq!(
+            parse("1", "1", "1", "32"),
+            out_of_range(IdRangeError::MachineId)
+        );
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, 0x3F_1234_5678, 3, 4);
+        let anonymized = sulid.anonymize();
+        assert_eq!(anonymized.timestamp_ms(), sulid.timestamp_ms());
+        assert_eq!(anonymized.random(), 0);
+        assert_eq!(anonymized.v2_worker_id(), 0);
+
+        let ids = [
+            Sulid::v2_from_parts(1_700_000_000_000, 900, 7),
+            Sulid::v2_from_parts(1_700_000_000_000, 100, 3),
+            Sulid::v2_from_parts(1_700_000_000_001, 500, 5),
+        ];
+        let anonymized = [
+            ids[0].anonymize_with_sequence(0),
+            ids[1].anonymize_with_sequence(1),
+            ids[2].anonymize_with_sequence(0),
+        ];
+        assert!(anonymized.windows(2).all(|pair| pair[0] < pair[1]));
+        for (id, anonymized) in ids.iter().zip(anonymized) {
+            assert_eq!(anonymized.timestamp_ms(), id.timestamp_ms());
+            assert_eq!(anonymized.v2_worker_id(), 0);
+        }
+        assert_eq!(anonymized[1].random(), 1);
+    }
+
+    #[test]
+    fn test_keyspace_fraction() {
+        assert_eq!(Sulid::nil().keyspace_fraction(), 0.0);
+        assert_eq!(Sulid::v2_from_parts(1, 0, 1023).keyspace_fraction(), 0.0);
+        assert_eq!(
+            Sulid::v2_from_parts(1, 1 << 68, 0).keyspace_fraction(),
+            0.25
+        );
+
+        let max_random = Sulid::v2_from_parts(0, bitmask!(Sulid::RAND_BITS => u128), 0);
+        assert!((max_random.keyspace_fraction() - 1.0).abs() < 1e-15);
+        assert!(Sulid::MAX.keyspace_fraction() <= 1.0);
+    }
+
+    #[test]
+    fn test_scan() {
+        let line = "2024-05-01T12:00:00Z INFO request 01D39ZY06FGSCTVN4T2V9PKHFZ done";
+        assert_eq!(Sulid::scan(line), Some((Sulid::example(), 34)));
+        assert_eq!(
+            Sulid::scan("01D39ZY06FGSCTVN4T2V9PKHFZ"),
+            Some((Sulid::example(), 0))
+        );
+
+        assert_eq!(Sulid::scan(""), None);
+        assert_eq!(
+            Sulid::scan("no SULID in this line at all, just words"),
+            None
+        );
+        assert_eq!(Sulid::scan("01D39ZY06FGSCTVN4T2V9PKHF"), None);
+        // Overflowing and non-ASCII text never matches.
+        assert_eq!(Sulid::scan("81D39ZY06FGSCTVN4T2V9PKHFZ"), None);
+        assert_eq!(Sulid::scan("é01D39ZY06FGSCTVN4T2V9PKHFé"), None);
+
+        let line = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ then 01D39ZY06FGSCTVN4T2V9PKHFZ";
+        assert_eq!(Sulid::scan(line), Some((Sulid::MAX, 0)));
+        // In a longer run, the leftmost valid window wins.
+        assert_eq!(
+            Sulid::scan("Z01D39ZY06FGSCTVN4T2V9PKHFZ"),
+            Some((Sulid::example(), 1))
+        );
+        assert_eq!(
+            Sulid::scan("001D39ZY06FGSCTVN4T2V9PKHFZ").map(|(_, offset)| offset),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_color_seed() {
+        let sulid = Sulid::example();
+        assert_eq!(sulid.color_seed(), 0xAFFD_549D);
+        assert_eq!(sulid.color_seed(), sulid.color_seed());
+
+        let (_, random, worker_id) = sulid.into();
+        assert_eq!(
+            Sulid::v2_from_parts(0, random, worker_id).color_seed(),
+            0xAFFD_549D
+        );
+        assert_ne!(
+            Sulid::v2_from_parts(0, random, worker_id ^ 1).color_seed(),
+            0xAFFD_549D
+        );
+        assert_ne!(
+            Sulid::v2_from_parts(0, random ^ (1 << 69), worker_id).color_seed(),
+            0xAFFD_549D
+        );
+    }
+
+    #[test]
+    fn test_worker_min_max() {
+        for worker_id in [0, 1, 511, 1023] {
+            let min = Sulid::worker_min(worker_id);
+            let max = Sulid::worker_max(worker_id);
+            assert_eq!((min.timestamp_ms(), min.random()), (0, 0));
+            assert_eq!(max.timestamp_ms(), bitmask!(Sulid::TIME_BITS => u64));
+            assert_eq!(max.random(), bitmask!(Sulid::RAND_BITS => u128));
+            assert_eq!(min.v2_worker_id(), worker_id);
+            assert_eq!(max.v2_worker_id(), worker_id);
+
+            for (timestamp_ms, random) in [
+                (0, 0),
+                (1_700_000_000_000, 42),
+                (
+                    bitmask!(Sulid::TIME_BITS => u64),
+                    bitmask!(Sulid::RAND_BITS => u128),
+                ),
+                (1, bitmask!(Sulid::RAND_BITS => u128)),
+            ] {
+                let sulid = Sulid::v2_from_parts(timestamp_ms, random, worker_id);
+                assert!(min <= sulid && sulid <= max);
+            }
+        }
+        assert_eq!(Sulid::worker_min(0), Sulid::MIN);
+        assert_eq!(Sulid::worker_max(1023), Sulid::MAX);
+        // Other workers' IDs interleave with the range.
+        assert!(Sulid::worker_min(7) < Sulid::v2_from_parts(1, 0, 3));
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn test_checksum() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, 0x3F_1234_5678_9ABC_DEF0, 3, 4);
+        let checksummed = sulid.with_checksum();
+        assert!(checksummed.verify_checksum());
+        assert_eq!(checksummed.timestamp_ms(), sulid.timestamp_ms());
+        assert_eq!(checksummed.worker_field(), sulid.worker_field());
+        assert_eq!(checksummed.random() >> 8, sulid.random() >> 8);
+        assert_eq!(checksummed.with_checksum(), checksummed);
+        assert!(Sulid::MIN.with_checksum().verify_checksum());
+        assert!(Sulid::MAX.with_checksum().verify_checksum());
+
+        for bit in 0..128 {
+            let corrupted = Sulid::from_u128(checksummed.u128() ^ (1 << bit));
+            assert!(
+                !corrupted.verify_checksum(),
+                "flipped bit {bit} went undetected"
+            );
+        }
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    #[test]
+    fn test_to_string_alloc() {
+        use alloc::string::String;
+
+        let sulid = Sulid::example();
+        assert_eq!(sulid.to_string(), "01D39ZY06FGSCTVN4T2V9PKHFZ");
+        assert_eq!(String::from(sulid), "01D39ZY06FGSCTVN4T2V9PKHFZ");
+        assert_eq!(sulid.to_string_lowercase(), "01d39zy06fgsctvn4t2v9pkhfz");
+        assert_eq!(sulid.time_prefix(), "01D39ZY06F");
+        assert_eq!(sulid.to_proto_bytes(), sulid.to_bytes());
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn test_display_no_std() {
+        use core::fmt::Write;
+
+        /// A fixed-capacity string on the stack.
+        struct StackString {
+            buf: [u8; 64],
+            len: usize,
+        }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Sulid::from_string(s)
-    }
-}
+        impl Write for StackString {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
 
-impl TryFrom<&'_ str> for Sulid {
-    type Error = DecodeError;
+        let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        let mut out = StackString {
+            buf: [0; 64],
+            len: 0,
+        };
+        write!(out, "{}|{}", sulid, Sulid::nil()).unwrap();
+        assert_eq!(
+            &out.buf[..out.len],
+            b"01D39ZY06FGSCTVN4T2V9PKHFZ|00000000000000000000000000"
+        );
 
-    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
-        Sulid::from_string(value)
+        // Formatting fails cleanly instead of allocating when the buffer is full.
+        assert!(write!(out, "{}", sulid).is_err());
     }
-}
 
-impl fmt::Display for Sulid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+    #[test]
+    fn test_example() {
         let mut buffer = [0; ULID_LEN];
-        write!(f, "{}", self.array_to_str(&mut buffer))
+        assert_eq!(
+            Sulid::example().array_to_str(&mut buffer),
+            "01D39ZY06FGSCTVN4T2V9PKHFZ"
+        );
+        assert_eq!(
+            Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"),
+            Ok(Sulid::example())
+        );
+        assert_eq!(Sulid::example().timestamp_ms(), 1_549_744_931_023);
+        assert_eq!(Sulid::example().v2_worker_id(), 511);
     }
-}
 
-mod tests {
-    use super::*;
+    #[test]
+    fn test_range() {
+        let start = Sulid::v1_from_parts(7, 3, 31, 30);
+        let end = Sulid::v1_from_parts(7, 4, 0, 3);
+        let mut count = 0;
+        let mut prev = None;
+        for sulid in range(start, end) {
+            assert!(start <= sulid && sulid < end);
+            assert!(prev < Some(sulid));
+            prev = Some(sulid);
+            count += 1;
+        }
+        assert_eq!(count, 5);
+        assert_eq!(prev, end.prev());
+
+        assert_eq!(range(start, start).count(), 0);
+        assert_eq!(range(end, start).count(), 0);
+        assert_eq!(range(Sulid::MAX.prev().unwrap(), Sulid::MAX).count(), 1);
+        assert_eq!(
+            range(Sulid::MIN, Sulid::MAX).take(3).last(),
+            Some(Sulid::from_u128(2))
+        );
+    }
 
     #[test]
-    fn test_static() {
-        let mut s = [0u8; ULID_LEN];
-        let s = Sulid::from_u128(0x41414141414141414141414141414141).array_to_str(&mut s);
-        let u = Sulid::from_string(&s).unwrap();
-        assert_eq!(s, "21850M2GA1850M2GA1850M2GA1");
-        assert_eq!(u.u128(), 0x41414141414141414141414141414141);
+    fn test_timeuuid_like_bytes() {
+        let sulid = Sulid::v2_from_parts(0xABCD_EF01_2345, u128::from(u64::MAX), 0x3FF);
+        assert_eq!(
+            u128::from_be_bytes(sulid.to_timeuuid_like_bytes()),
+            0xABCD_EF01_2345_7FFF_BFFF_FFFF_FFFF_FFFF
+        );
+        let sulid = Sulid::v2_from_parts(1, 0x3F << 64, 0);
+        assert_eq!(
+            u128::from_be_bytes(sulid.to_timeuuid_like_bytes()),
+            0x0000_0000_0001_7000_8000_0000_0000_0000
+        );
+
+        let ids = [
+            Sulid::v1_from_parts(1_000, bitmask!(Sulid::RAND_BITS => u128), 31, 31),
+            Sulid::v1_from_parts(1_001, 0, 0, 0),
+            Sulid::v2_from_parts(1_002, 42, 7),
+            Sulid::v2_from_parts(bitmask!(Sulid::TIME_BITS => u64), 0, 0),
+        ];
+        for w in ids.windows(2) {
+            assert!(w[0] < w[1]);
+            assert!(w[0].to_timeuuid_like_bytes() < w[1].to_timeuuid_like_bytes());
+        }
     }
 
     #[test]
@@ -481,10 +2661,394 @@ mod tests {
 
     #[test]
     fn test_increment_overflow() {
-        let sulid = Sulid::from_u128(u128::max_value());
+        let sulid = Sulid::from_u128(u128::MAX);
         assert!(sulid.increment().is_none());
     }
 
+    #[test]
+    fn test_min_max() {
+        assert_eq!(Sulid::MIN, Sulid::nil());
+        assert_eq!(Sulid::MAX.u128(), u128::MAX);
+        assert_eq!(Sulid::MIN.prev(), None);
+        assert_eq!(Sulid::MAX.next(), None);
+        for &(_, _, _, _, value, _) in crate::test_vectors() {
+            let sulid = Sulid::from_u128(value);
+            assert!(Sulid::MIN <= sulid && sulid <= Sulid::MAX);
+        }
+    }
+
+    #[test]
+    fn test_next_prev() {
+        assert_eq!(Sulid::nil().prev(), None);
+        assert_eq!(Sulid::from_u128(u128::MAX).next(), None);
+
+        let sulid = Sulid::v1_from_parts(1, bitmask!(Sulid::RAND_BITS => u128), 31, 31);
+        let next = sulid.next().unwrap();
+        assert!(next > sulid);
+        assert_eq!(next, Sulid::v1_from_parts(2, 0, 0, 0));
+        assert_eq!(next.prev(), Some(sulid));
+        assert!(sulid.increment().is_none());
+    }
+
+    #[test]
+    fn test_random_bits() {
+        const MAX_RAND: u128 = bitmask!(Sulid::RAND_BITS => u128);
+        let sulid = Sulid::v1_from_parts(0xABCDEF, MAX_RAND, 17, 29);
+        for n in [0, 1, 32, 38, 69, 70] {
+            assert_eq!(sulid.random_high_bits(n), bitmask!(n => u128));
+            assert_eq!(sulid.random_low_bits(n), bitmask!(n => u128));
+        }
+
+        let random = (0xDEAD_BEEF << 38) | 0x12_3456_789A;
+        let sulid = Sulid::v1_from_parts(0xABCDEF, random, 17, 29);
+        assert_eq!(sulid.random_high_bits(32), 0xDEAD_BEEF);
+        assert_eq!(sulid.random_low_bits(38), 0x12_3456_789A);
+        assert_eq!(sulid.random_high_bits(70), random);
+        assert_eq!(sulid.random_low_bits(70), random);
+
+        for (n, value) in [
+            (0, 1),
+            (1, 1),
+            (16, 0xFFFF),
+            (32, 0xCAFE_BABE),
+            (70, MAX_RAND),
+        ] {
+            let updated = sulid.with_random_high_bits(n, value);
+            assert_eq!(updated.random_high_bits(n), value & bitmask!(n => u128));
+            assert_eq!(
+                updated.random_low_bits(70 - n),
+                sulid.random_low_bits(70 - n)
+            );
+            assert_eq!(updated.timestamp_ms(), 0xABCDEF);
+            assert_eq!(updated.v1_data_center_id(), 17);
+            assert_eq!(updated.v1_machine_id(), 29);
+        }
+
+        // Overflow bits of the value don't leak into the timestamp.
+        let updated = Sulid::nil().with_random_high_bits(8, u128::MAX);
+        assert_eq!(updated.timestamp_ms(), 0);
+        assert_eq!(updated.v2_worker_id(), 0);
+        assert_eq!(updated.random_high_bits(8), 0xFF);
+    }
+
+    #[test]
+    #[should_panic(expected = "n must be in the range 0-70")]
+    fn random_bits_out_of_range() {
+        let _ = Sulid::nil().random_high_bits(71);
+    }
+
+    #[test]
+    fn test_with_worker() {
+        let random = bitmask!(Sulid::RAND_BITS => u128) - 12345;
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, random, 1, 2);
+
+        let v2 = sulid.v2_with_worker_id(777);
+        assert_eq!(v2.timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(v2.random(), random);
+        assert_eq!(v2.v2_worker_id(), 777);
+        assert_eq!(sulid.v2_with_worker_id(1024 + 5).v2_worker_id(), 5);
+
+        let v1 = sulid.v1_with_data_center_and_machine(31, 30);
+        assert_eq!(v1.timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(v1.random(), random);
+        assert_eq!((v1.v1_data_center_id(), v1.v1_machine_id()), (31, 30));
+        let masked = sulid.v1_with_data_center_and_machine(32 + 3, 32 + 4);
+        assert_eq!((masked.v1_data_center_id(), masked.v1_machine_id()), (3, 4));
+        assert_eq!(masked.random(), random);
+    }
+
+    #[test]
+    fn test_time_bucket() {
+        const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+        let (start, end) = Sulid::bucket_range(19_675, DAY_MS);
+        let ids = [
+            Sulid::v1_from_parts(19_675 * DAY_MS, 0, 0, 0),
+            Sulid::v2_from_parts(19_675 * DAY_MS + 12_345, 42, 7),
+            Sulid::v1_from_parts(
+                19_676 * DAY_MS - 1,
+                bitmask!(Sulid::RAND_BITS => u128),
+                31,
+                31,
+            ),
+        ];
+        for sulid in ids {
+            assert_eq!(sulid.time_bucket(DAY_MS), 19_675);
+            assert!(start <= sulid && sulid <= end);
+        }
+        assert_eq!(start, ids[0]);
+        assert_eq!(end, ids[2]);
+        assert_eq!(end.next().unwrap().time_bucket(DAY_MS), 19_676);
+        assert_eq!(start.prev().unwrap().time_bucket(DAY_MS), 19_674);
+
+        let max_ts = bitmask!(Sulid::TIME_BITS => u64);
+        assert_eq!(Sulid::MAX.time_bucket(1), max_ts);
+        assert_eq!(Sulid::bucket_range(max_ts, 1).1, Sulid::MAX);
+        assert_eq!(Sulid::bucket_range(1, max_ts).1, Sulid::MAX);
+        assert_eq!(Sulid::bucket_range(u64::MAX, 2), (Sulid::MAX, Sulid::MAX));
+    }
+
+    #[test]
+    fn test_quantize_time() {
+        const MINUTE_MS: u64 = 60 * 1000;
+        const HOUR_MS: u64 = 60 * MINUTE_MS;
+        let sulid = Sulid::v1_from_parts(1_700_000_123_456, 0x2A_DEAD_BEEF, 17, 29);
+
+        let minute = sulid.quantize_time(MINUTE_MS);
+        assert_eq!(minute.timestamp_ms(), 1_700_000_100_000);
+        assert_eq!(minute.random(), sulid.random());
+        assert_eq!(minute.v2_worker_id(), sulid.v2_worker_id());
+
+        let hour = sulid.quantize_time(HOUR_MS);
+        assert_eq!(hour.timestamp_ms(), 1_699_999_200_000);
+        assert_eq!(
+            hour,
+            Sulid::v1_from_parts(1_699_999_200_000, 0x2A_DEAD_BEEF, 17, 29)
+        );
+        assert!(hour <= minute && minute <= sulid);
+
+        assert_eq!(sulid.quantize_time(1), sulid);
+        assert_eq!(sulid.quantize_time(0), sulid);
+        assert_eq!(sulid.quantize_time(u64::MAX).timestamp_ms(), 0);
+    }
+
+    #[test]
+    fn test_from_string_strict() {
+        let valid = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+        assert_eq!(
+            Sulid::from_string_strict(valid),
+            Ok(Sulid::from_string(valid).unwrap())
+        );
+        assert_eq!(
+            Sulid::from_string_strict("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Ok(Sulid::MAX)
+        );
+
+        // The lenient path silently drops the overflowing top bits.
+        let overflow = "8ZZZZZZZZZZZZZZZZZZZZZZZZZ";
+        assert_eq!(
+            Sulid::from_string(overflow),
+            Sulid::from_string("0ZZZZZZZZZZZZZZZZZZZZZZZZZ")
+        );
+        assert_eq!(
+            Sulid::from_string_strict(overflow),
+            Err(StrictDecodeError::Overflow)
+        );
+        assert_eq!(
+            Sulid::from_string_strict("zZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Err(StrictDecodeError::Overflow)
+        );
+        assert_eq!(
+            Sulid::from_string_strict("01D39ZY06FGSCTVN4T2V9PKHF"),
+            Err(StrictDecodeError::InvalidLength)
+        );
+        assert_eq!(
+            Sulid::from_string_strict("01D39ZY06FGSCTVN4T2V9PKHFU"),
+            Err(StrictDecodeError::InvalidChar)
+        );
+        assert_eq!(
+            StrictDecodeError::from(DecodeError::InvalidChar),
+            StrictDecodeError::InvalidChar
+        );
+    }
+
+    #[test]
+    fn test_quick_validate() {
+        assert_eq!(Sulid::quick_validate("01D39ZY06FGSCTVN4T2V9PKHFZ"), Ok(()));
+        assert_eq!(Sulid::quick_validate("01d39zy06fgsctvn4t2v9pkhfz"), Ok(()));
+        assert_eq!(
+            Sulid::quick_validate("01D39ZY06FGSCTVN4T2V9PKHF"),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            Sulid::quick_validate("01D39ZY06FGSCTVN4T2V9PKHFZZ"),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(Sulid::quick_validate(""), Err(DecodeError::InvalidLength));
+        for invalid in [
+            "01D39ZY06FGSCTUN4T2V9PKHFZ",
+            "01D39ZY06FGSCTVN4T2V9PKHFl",
+            "01D39ZY06FGSCTVN4T2V9PKHF-",
+        ] {
+            assert_eq!(
+                Sulid::quick_validate(invalid),
+                Err(DecodeError::InvalidChar)
+            );
+            assert_eq!(Sulid::from_string(invalid), Err(DecodeError::InvalidChar));
+        }
+    }
+
+    #[test]
+    fn test_from_string_verbose() {
+        let valid = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+        assert_eq!(Sulid::from_string_verbose(valid), Ok(Sulid::example()));
+        assert_eq!(
+            Sulid::from_string_verbose("01d39zy06fgsctvn4t2v9pkhfz"),
+            Ok(Sulid::example())
+        );
+
+        let err = Sulid::from_string_verbose("01D39ZY06FGSCTVN4T2V9PKHFO").unwrap_err();
+        assert_eq!(
+            err,
+            SulidDecodeError::InvalidChar {
+                position: 25,
+                character: 'O'
+            }
+        );
+        assert_eq!(err.position(), Some(25));
+        assert_eq!(DecodeError::from(err), DecodeError::InvalidChar);
+        for (s, position) in [
+            ("i1D39ZY06FGSCTVN4T2V9PKHFZ", 0),
+            ("01D3\u{e9}ZY06FGSCTVN4T2V9PKHF", 4),
+            ("01D39-", 5),
+        ] {
+            assert_eq!(
+                Sulid::from_string_verbose(s).unwrap_err().position(),
+                Some(position),
+                "{}",
+                s
+            );
+        }
+
+        let err = Sulid::from_string_verbose("01D39ZY06FGSCTVN4T2V9PKHF").unwrap_err();
+        assert_eq!(
+            err,
+            SulidDecodeError::InvalidLength {
+                expected: 26,
+                actual: 25
+            }
+        );
+        assert_eq!(err.position(), None);
+        assert_eq!(DecodeError::from(err), DecodeError::InvalidLength);
+        assert_eq!(
+            Sulid::from_string_verbose(""),
+            Err(SulidDecodeError::InvalidLength {
+                expected: 26,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_timestamp_from_str_prefix() {
+        let sulid = Sulid::v2_from_parts(1_700_000_000_000, 42, 7);
+        let mut buffer = [0; ULID_LEN];
+        let full = sulid.array_to_str(&mut buffer);
+
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix(full),
+            Ok(1_700_000_000_000)
+        );
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix(&full[..10]),
+            Ok(1_700_000_000_000)
+        );
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix("01d39zy06f"),
+            Sulid::timestamp_from_str_prefix("01D39ZY06F")
+        );
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix("7ZZZZZZZZZ"),
+            Ok(bitmask!(48 => u64))
+        );
+        assert_eq!(Sulid::timestamp_from_str_prefix("0000000001UUUU"), Ok(1));
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix("01D39ZY06"),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix("01D39ZY0UF"),
+            Err(DecodeError::InvalidChar)
+        );
+        assert_eq!(
+            Sulid::timestamp_from_str_prefix("01D39ZY0\u{e9}F"),
+            Err(DecodeError::InvalidChar)
+        );
+    }
+
+    #[test]
+    fn test_worker_field() {
+        let sulid = Sulid::v1_from_parts(1_700_000_000_000, 0x2A_DEAD_BEEF, 17, 29);
+        assert_eq!(sulid.worker_field(), (17 << 5) | 29);
+
+        for value in [0, 1, 512, 1023] {
+            let updated = sulid.with_worker_field(value);
+            assert_eq!(updated.worker_field(), value);
+            assert_eq!(updated.timestamp_ms(), sulid.timestamp_ms());
+            assert_eq!(updated.random(), sulid.random());
+        }
+        let max = sulid.with_worker_field(1023);
+        assert_eq!((max.v1_data_center_id(), max.v1_machine_id()), (31, 31));
+        assert_eq!(max.v2_worker_id(), 1023);
+
+        // Overflow bits are discarded.
+        assert_eq!(sulid.with_worker_field(1024 | 7).worker_field(), 7);
+        assert_eq!(sulid.with_worker_field(u16::MAX).worker_field(), 1023);
+    }
+
+    #[test]
+    fn test_worker_split() {
+        let sulid = Sulid::v2_from_parts_hierarchical(1_700_000_000_000, 42, 6, 77, 3);
+        assert_eq!(sulid.worker_split(3), (6, 77));
+        assert_eq!(sulid.v2_worker_id(), (6 << 7) | 77);
+        assert_eq!(sulid.timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(sulid.random(), 42);
+
+        let (high, low) = sulid.worker_split(3);
+        assert_eq!(
+            Sulid::v2_from_parts_hierarchical(sulid.timestamp_ms(), sulid.random(), high, low, 3),
+            sulid
+        );
+
+        let v1 = Sulid::v1_from_parts(1, 2, 17, 29);
+        assert_eq!(v1.worker_split(5), (17, 29));
+        assert_eq!(v1.worker_split(10), (v1.v2_worker_id(), 0));
+        assert_eq!(v1.worker_split(0), (0, v1.v2_worker_id()));
+
+        // Overflow bits are discarded.
+        let sulid = Sulid::v2_from_parts_hierarchical(1, 2, 0b1111, 0xFF, 3);
+        assert_eq!(sulid.worker_split(3), (0b111, 0x7F));
+    }
+
+    #[test]
+    #[should_panic(expected = "high_bits must be in the range 0-10")]
+    fn test_worker_split_out_of_range() {
+        Sulid::nil().worker_split(11);
+    }
+
+    #[test]
+    fn test_from_any() {
+        let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        let formats = [
+            "01D39ZY06FGSCTVN4T2V9PKHFZ",
+            "01d39zy06fgsctvn4t2v9pkhfz",
+            "0168D3FF00CF8659ADD49A16D369C5FF",
+            "0168d3ff00cf8659add49a16d369c5ff",
+            "1873526660931231682191479120471901695",
+        ];
+        for s in formats {
+            assert_eq!(Sulid::from_any(s), Ok(sulid), "{}", s);
+        }
+
+        assert_eq!(Sulid::from_any("0"), Ok(Sulid::nil()));
+        assert_eq!(
+            Sulid::from_any("340282366920938463463374607431768211455"),
+            Ok(Sulid::from_u128(u128::MAX))
+        );
+        assert_eq!(Sulid::from_any(""), Err(DecodeError::InvalidLength));
+        assert_eq!(
+            Sulid::from_any("340282366920938463463374607431768211456"),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            Sulid::from_any("0168d3ff00cf8659add49a16d369c5fg"),
+            Err(DecodeError::InvalidChar)
+        );
+        assert_eq!(
+            Sulid::from_any("not-a-sulid"),
+            Err(DecodeError::InvalidChar)
+        );
+    }
+
     #[test]
     fn can_into_thing() {
         let sulid = Sulid::from_str("01FKMG6GAG0PJANMWFN84TNXCD").unwrap();
@@ -508,46 +3072,410 @@ mod tests {
     fn default_is_nil() {
         assert_eq!(Sulid::default(), Sulid::nil());
     }
+
+    #[test]
+    fn nil_const_in_patterns() {
+        const DEFAULT_ID: Sulid = Sulid::NIL;
+        assert_eq!(DEFAULT_ID, Sulid::default());
+        assert!(Sulid::NIL.is_nil());
+
+        let describe = |id: Sulid| match id {
+            Sulid::NIL => "nil",
+            _ => "set",
+        };
+        assert_eq!(describe(Sulid::default()), "nil");
+        assert_eq!(describe(Sulid::example()), "set");
+    }
+
+    #[test]
+    fn from_parts_checked_rejects_out_of_range() {
+        const MAX_TS: u64 = bitmask!(Sulid::TIME_BITS => u64);
+        const MAX_RAND: u128 = bitmask!(Sulid::RAND_BITS => u128);
+
+        assert_eq!(
+            Sulid::v1_from_parts_checked(MAX_TS, MAX_RAND, 31, 31),
+            Ok(Sulid::v1_from_parts(MAX_TS, MAX_RAND, 31, 31))
+        );
+        assert_eq!(
+            Sulid::v1_from_parts_checked(MAX_TS + 1, 0, 0, 0),
+            Err(IdRangeError::Timestamp)
+        );
+        assert_eq!(
+            Sulid::v1_from_parts_checked(0, MAX_RAND + 1, 0, 0),
+            Err(IdRangeError::Random)
+        );
+        assert_eq!(
+            Sulid::v1_from_parts_checked(0, 0, 32, 0),
+            Err(IdRangeError::DataCenterId)
+        );
+        assert_eq!(
+            Sulid::v1_from_parts_checked(0, 0, 0, 32),
+            Err(IdRangeError::MachineId)
+        );
+
+        assert_eq!(
+            Sulid::v2_from_parts_checked(MAX_TS, MAX_RAND, 1023),
+            Ok(Sulid::v2_from_parts(MAX_TS, MAX_RAND, 1023))
+        );
+        assert_eq!(
+            Sulid::v2_from_parts_checked(MAX_TS + 1, 0, 0),
+            Err(IdRangeError::Timestamp)
+        );
+        assert_eq!(
+            Sulid::v2_from_parts_checked(0, MAX_RAND + 1, 0),
+            Err(IdRangeError::Random)
+        );
+        assert_eq!(
+            Sulid::v2_from_parts_checked(0, 0, 1024),
+            Err(IdRangeError::WorkerId)
+        );
+    }
+
+    #[test]
+    fn test_likely_version() {
+        assert_eq!(
+            Sulid::v2_from_parts(1, 1, 1023).likely_version(),
+            SulidVersion::V2
+        );
+        assert_eq!(
+            Sulid::v2_from_parts(1, 1, 31).likely_version(),
+            SulidVersion::Ambiguous
+        );
+        // A V1 ID from data center 0 cannot be told apart from a small V2 worker ID,
+        // and one from any other data center looks like a large V2 worker ID.
+        assert_eq!(
+            Sulid::v1_from_parts(1, 1, 0, 9).likely_version(),
+            SulidVersion::Ambiguous
+        );
+        assert_eq!(
+            Sulid::v1_from_parts(1, 1, 3, 9).likely_version(),
+            SulidVersion::V2
+        );
+    }
+
+    #[test]
+    fn worker_id_round_trip() {
+        for (data_center_id, machine_id) in [(0, 0), (1, 1), (3, 5), (31, 0), (0, 31), (31, 31)] {
+            let worker_id = combine_worker_id(data_center_id, machine_id);
+            assert_eq!(split_worker_id(worker_id), (data_center_id, machine_id));
+
+            let v1 = Sulid::v1_from_parts(1, 1, data_center_id, machine_id);
+            let v2 = Sulid::v2_from_parts(1, 1, worker_id);
+            assert_eq!(v1, v2);
+        }
+    }
 }
 
-#[cfg(feature = "std")]
-pub(crate) mod std_feature {
-    use crate::{sulid::bitmask, Sulid};
-    use std::time::{Duration, SystemTime};
+#[cfg(feature = "alloc")]
+pub(crate) mod alloc_feature {
+    use crate::{
+        sulid::{BASE64URL_ALPHABET, BASE64URL_LEN, TIME_PREFIX_LEN},
+        DecodeError, Sulid, ULID_LEN,
+    };
+    use alloc::borrow::{Cow, ToOwned};
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
 
     impl From<Sulid> for String {
         fn from(sulid: Sulid) -> String {
             sulid.to_string()
         }
-    }
+    }
+
+    impl Sulid {
+        /// Returns the prefix of the canonical string that only depends on the timestamp.
+        ///
+        /// The first Crockford Base32 character of a 26-character string carries the top
+        /// 3 bits of the 128-bit value and each following character carries 5 bits, so the
+        /// first 10 characters encode exactly the 48-bit timestamp (3 + 9 × 5 = 48) and
+        /// nothing else. Sulids from the same millisecond therefore share the same prefix,
+        /// and prefixes sort in time order.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        /// assert_eq!(sulid.time_prefix(), "01D39ZY06F");
+        /// ```
+        pub fn time_prefix(&self) -> String {
+            let mut buffer = [0; ULID_LEN];
+            self.array_to_str(&mut buffer)[..TIME_PREFIX_LEN].to_owned()
+        }
+
+        /// Encodes this Sulid for a protobuf `bytes` field
+        ///
+        /// The encoding is the 16 bytes of the 128-bit value in big-endian order, the same
+        /// as [`Sulid::to_bytes`] and the convention of common protobuf ULID libraries.
+        /// Byte-wise comparison of the encoded values therefore matches the Sulid order.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::from_u128(0x0102);
+        /// let bytes = sulid.to_proto_bytes();
+        /// assert_eq!(bytes.len(), 16);
+        /// assert_eq!(bytes[14..], [0x01, 0x02]);
+        /// assert_eq!(Sulid::from_proto_bytes(&bytes), Ok(sulid));
+        /// ```
+        pub fn to_proto_bytes(&self) -> Vec<u8> {
+            self.to_bytes().to_vec()
+        }
+
+        /// Creates a Crockford Base32 encoded string that represents this Sulid
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+        /// let sulid = Sulid::from_string(text).unwrap();
+        ///
+        /// assert_eq!(&sulid.to_string(), text);
+        /// ```
+        #[allow(clippy::inherent_to_string_shadow_display)] // Significantly faster than Display::to_string
+        pub fn to_string(&self) -> String {
+            let mut buffer = [0; ULID_LEN];
+            String::from(&*self.array_to_str(&mut buffer))
+        }
+
+        /// Creates a lowercase Crockford Base32 encoded string that represents this Sulid
+        ///
+        /// [`Sulid::from_string`] accepts both cases, so the lowercase form round-trips.
+        /// Since lowercasing preserves the relative order of the Crockford alphabet,
+        /// lowercase strings still sort in the same order as their Sulids among themselves,
+        /// but must not be compared with uppercase ones.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        /// assert_eq!(sulid.to_string_lowercase(), "01d39zy06fgsctvn4t2v9pkhfz");
+        /// ```
+        pub fn to_string_lowercase(&self) -> String {
+            let mut buffer = [0; ULID_LEN];
+            self.array_to_str(&mut buffer).to_ascii_lowercase()
+        }
+
+        /// Renders the 128-bit value of this Sulid in base 10
+        ///
+        /// Some legacy systems store 128-bit IDs as decimal strings. Unlike the Crockford
+        /// Base32 form, decimal strings have no fixed length, so they do not sort in the
+        /// same order as their Sulids when compared as strings, e.g. `"9" > "10"`. Use
+        /// [`Sulid::from_decimal`] to parse them back.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::example();
+        /// assert_eq!(sulid.to_decimal(), "1873526660931231682191479120471901695");
+        /// assert_eq!(Sulid::from_decimal(&sulid.to_decimal()), Ok(sulid));
+        /// ```
+        pub fn to_decimal(&self) -> String {
+            self.u128().to_string()
+        }
+
+        /// Parses a Sulid and returns it together with its canonical string form.
+        ///
+        /// The string is borrowed from the input when it is already canonical
+        /// (uppercase Crockford Base32), so callers can reuse it without re-encoding.
+        /// Otherwise, e.g. for lowercase input, an owned normalized string is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use std::borrow::Cow;
+        /// use sulid::Sulid;
+        ///
+        /// let (sulid, s) = Sulid::parse_canonical("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        /// assert!(matches!(s, Cow::Borrowed(_)));
+        ///
+        /// let (lower, s) = Sulid::parse_canonical("01d39zy06fgsctvn4t2v9pkhfz").unwrap();
+        /// assert!(matches!(s, Cow::Owned(_)));
+        /// assert_eq!(s, "01D39ZY06FGSCTVN4T2V9PKHFZ");
+        /// assert_eq!(sulid, lower);
+        /// ```
+        pub fn parse_canonical(s: &str) -> Result<(Sulid, Cow<'_, str>), DecodeError> {
+            let sulid = Sulid::from_string(s)?;
+            let mut buffer = [0; ULID_LEN];
+            let canonical = sulid.array_to_str(&mut buffer);
+            if canonical == s {
+                Ok((sulid, Cow::Borrowed(s)))
+            } else {
+                Ok((sulid, Cow::Owned(canonical.to_owned())))
+            }
+        }
+
+        /// Creates a 22-character base64url string (RFC 4648, without padding) of the
+        /// 16 big-endian bytes of this Sulid
+        ///
+        /// The result is shorter than the Crockford Base32 form, but since the base64url
+        /// alphabet is not in ASCII order, the strings do not sort like their Sulids.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::example();
+        /// assert_eq!(sulid.to_base64url(), "AWjT_wDPhlmt1JoW02nF_w");
+        /// assert_eq!(Sulid::from_base64url(&sulid.to_base64url()), Ok(sulid));
+        /// ```
+        pub fn to_base64url(&self) -> String {
+            let value = self.u128();
+            (0..BASE64URL_LEN)
+                .map(|i| {
+                    // The 128 bits are followed by 4 zero bits to fill 22 six-bit digits.
+                    let index = match 122_usize.checked_sub(6 * i) {
+                        Some(shift) => (value >> shift) & 0x3F,
+                        None => (value & 0x3) << 4,
+                    };
+                    BASE64URL_ALPHABET[index as usize] as char
+                })
+                .collect()
+        }
+
+        /// Returns the shortest suffix of the canonical string that tells this Sulid apart
+        /// from all other Sulids in `context`
+        ///
+        /// Sulids from the same time window share their leading timestamp characters, so the
+        /// trailing characters are the ones that differ, much like git's short hashes but
+        /// from the other end. The suffix is at least 1 character long; Sulids in `context`
+        /// equal to this one are ignored. It is only unique within `context`.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let a = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+        /// let b = Sulid::from_string("01D39ZY06FGSCTVN4T2V9PKHAZ").unwrap();
+        /// assert_eq!(a.short_display(&[a, b]), "FZ");
+        /// assert_eq!(a.short_display(&[]), "Z");
+        /// ```
+        pub fn short_display(&self, context: &[Sulid]) -> String {
+            let mut buffer = [0; ULID_LEN];
+            let encoded = self.array_to_str(&mut buffer);
+            let len = context
+                .iter()
+                .filter(|other| *other != self)
+                .map(|other| {
+                    let mut other_buffer = [0; ULID_LEN];
+                    let other = other.array_to_str(&mut other_buffer);
+                    // The length of the common suffix plus one distinguishing character.
+                    encoded
+                        .bytes()
+                        .rev()
+                        .zip(other.bytes().rev())
+                        .take_while(|(a, b)| a == b)
+                        .count()
+                        + 1
+                })
+                .max()
+                .unwrap_or(1);
+            encoded[ULID_LEN - len..].to_owned()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) mod std_feature {
+    use crate::{
+        sulid::bitmask, DecodeError, IdRangeError, ParseError, StrictDecodeError, Sulid,
+        SulidDecodeError, TimeUnit, ULID_LEN,
+    };
+    use rand::Rng;
+    use std::time::{Duration, SystemTime};
+
+    impl std::error::Error for IdRangeError {}
+
+    impl std::error::Error for StrictDecodeError {}
+
+    impl std::error::Error for SulidDecodeError {}
+
+    impl std::error::Error for ParseError {}
+
+    impl Sulid {
+        /// Creates a new Sulid with the current time (UTC)
+        ///
+        /// Using this function to generate Sulids will not guarantee monotonic sort order.
+        /// See [sulid::SulidGenerator] for a monotonic sort order.
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let my_sulid = Sulid::v1_new(0, 0);
+        /// ```
+        pub fn v1_new(data_center_id: u8, machine_id: u8) -> Sulid {
+            Sulid::v1_from_datetime(now(), data_center_id, machine_id)
+        }
+
+        /// Creates a new Sulid with the current time (UTC)
+        ///
+        /// Using this function to generate Sulids will not guarantee monotonic sort order.
+        /// See [sulid::SulidGenerator] for a monotonic sort order.
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let my_sulid = Sulid::v2_new(0);
+        /// ```
+        pub fn v2_new(worker_id: u16) -> Sulid {
+            Sulid::v2_from_datetime(now(), worker_id)
+        }
 
-    impl Sulid {
-        /// Creates a new Sulid with the current time (UTC)
+        /// Creates the smallest Sulid of the worker in the current time bucket
+        ///
+        /// The current time is rounded down to a multiple of `resolution_ms`, and the random
+        /// section is zeroed. The result is a deterministic lower bound for queries, e.g.
+        /// "all IDs of this worker since the start of the current hour". A `resolution_ms`
+        /// of zero is treated as one.
         ///
-        /// Using this function to generate Sulids will not guarantee monotonic sort order.
-        /// See [sulid::SulidGenerator] for a monotonic sort order.
         /// # Example
         /// ```rust
         /// use sulid::Sulid;
         ///
-        /// let my_sulid = Sulid::v1_new(0, 0);
+        /// const HOUR_MS: u64 = 60 * 60 * 1000;
+        /// let floor = Sulid::floor_now(HOUR_MS, 7);
+        /// assert_eq!(floor.timestamp_ms() % HOUR_MS, 0);
+        /// assert_eq!((floor.random(), floor.v2_worker_id()), (0, 7));
         /// ```
-        pub fn v1_new(data_center_id: u8, machine_id: u8) -> Sulid {
-            Sulid::v1_from_datetime(now(), data_center_id, machine_id)
+        pub fn floor_now(resolution_ms: u64, worker_id: u16) -> Sulid {
+            Sulid::now_bucket_range(resolution_ms)
+                .0
+                .v2_with_worker_id(worker_id)
         }
 
-        /// Creates a new Sulid with the current time (UTC)
+        /// Creates the largest Sulid of the worker in the current time bucket
+        ///
+        /// This is the counterpart of [`Sulid::floor_now`]: the timestamp is the last
+        /// millisecond of the current `resolution_ms`-wide bucket, and all random bits are
+        /// set. A `resolution_ms` of zero is treated as one.
         ///
-        /// Using this function to generate Sulids will not guarantee monotonic sort order.
-        /// See [sulid::SulidGenerator] for a monotonic sort order.
         /// # Example
         /// ```rust
         /// use sulid::Sulid;
         ///
-        /// let my_sulid = Sulid::v2_new(0);
+        /// const HOUR_MS: u64 = 60 * 60 * 1000;
+        /// let ceil = Sulid::ceil_now(HOUR_MS, 7);
+        /// assert_eq!(ceil.timestamp_ms() % HOUR_MS, HOUR_MS - 1);
+        /// assert_eq!(ceil.v2_worker_id(), 7);
         /// ```
-        pub fn v2_new(worker_id: u16) -> Sulid {
-            Sulid::v2_from_datetime(now(), worker_id)
+        pub fn ceil_now(resolution_ms: u64, worker_id: u16) -> Sulid {
+            Sulid::now_bucket_range(resolution_ms)
+                .1
+                .v2_with_worker_id(worker_id)
+        }
+
+        /// Gets the bounds of the `resolution_ms`-wide bucket containing the current time.
+        fn now_bucket_range(resolution_ms: u64) -> (Sulid, Sulid) {
+            let timestamp = now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO)
+                .as_millis();
+            let timestamp_ms = (timestamp & bitmask!(Self::TIME_BITS => u128)) as u64;
+            let resolution_ms = resolution_ms.max(1);
+            Sulid::bucket_range(timestamp_ms / resolution_ms, resolution_ms)
         }
 
         /// Creates a new Sulid using data from the given random number generator
@@ -575,6 +3503,9 @@ pub(crate) mod std_feature {
         /// This will take the maximum of the `[SystemTime]` argument and `[SystemTime::UNIX_EPOCH]`
         /// as earlier times are not valid for a Sulid timestamp
         ///
+        /// NOTE: Timestamps that don't fit into 48 bits (after the year 10889) are silently
+        /// truncated. Use [`Sulid::v1_from_datetime_checked`] to reject them instead.
+        ///
         /// # Example
         /// ```rust
         /// use std::time::{SystemTime, Duration};
@@ -598,6 +3529,9 @@ pub(crate) mod std_feature {
         /// This will take the maximum of the `[SystemTime]` argument and `[SystemTime::UNIX_EPOCH]`
         /// as earlier times are not valid for a Sulid timestamp
         ///
+        /// NOTE: Timestamps that don't fit into 48 bits (after the year 10889) are silently
+        /// truncated. Use [`Sulid::v2_from_datetime_checked`] to reject them instead.
+        ///
         /// # Example
         /// ```rust
         /// use std::time::{SystemTime, Duration};
@@ -609,6 +3543,60 @@ pub(crate) mod std_feature {
             Sulid::v2_from_datetime_with_source(datetime, &mut rand::thread_rng(), worker_id)
         }
 
+        /// Creates a new Sulid with the given datetime, rejecting out-of-range values
+        ///
+        /// Unlike [`Sulid::v1_from_datetime`], an [`IdRangeError::Timestamp`] is returned
+        /// if the datetime is too far in the future to fit into 48 bits of milliseconds.
+        /// Times before the Unix epoch are still clamped to the epoch.
+        ///
+        /// # Example
+        /// ```rust
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::{IdRangeError, Sulid};
+        ///
+        /// assert!(Sulid::v1_from_datetime_checked(SystemTime::now(), 0, 0).is_ok());
+        ///
+        /// let too_late = SystemTime::UNIX_EPOCH + Duration::from_millis(1 << 48);
+        /// assert_eq!(Sulid::v1_from_datetime_checked(too_late, 0, 0), Err(IdRangeError::Timestamp));
+        /// ```
+        pub fn v1_from_datetime_checked(
+            datetime: SystemTime,
+            data_center_id: u8,
+            machine_id: u8,
+        ) -> Result<Sulid, IdRangeError> {
+            let random = rand::thread_rng().gen::<u128>() & bitmask!(Self::RAND_BITS => u128);
+            Sulid::v1_from_parts_checked(
+                checked_timestamp_ms(datetime)?,
+                random,
+                data_center_id,
+                machine_id,
+            )
+        }
+
+        /// Creates a new Sulid with the given datetime, rejecting out-of-range values
+        ///
+        /// Unlike [`Sulid::v2_from_datetime`], an [`IdRangeError::Timestamp`] is returned
+        /// if the datetime is too far in the future to fit into 48 bits of milliseconds.
+        /// Times before the Unix epoch are still clamped to the epoch.
+        ///
+        /// # Example
+        /// ```rust
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::{IdRangeError, Sulid};
+        ///
+        /// assert!(Sulid::v2_from_datetime_checked(SystemTime::now(), 0).is_ok());
+        ///
+        /// let too_late = SystemTime::UNIX_EPOCH + Duration::from_millis(1 << 48);
+        /// assert_eq!(Sulid::v2_from_datetime_checked(too_late, 0), Err(IdRangeError::Timestamp));
+        /// ```
+        pub fn v2_from_datetime_checked(
+            datetime: SystemTime,
+            worker_id: u16,
+        ) -> Result<Sulid, IdRangeError> {
+            let random = rand::thread_rng().gen::<u128>() & bitmask!(Self::RAND_BITS => u128);
+            Sulid::v2_from_parts_checked(checked_timestamp_ms(datetime)?, random, worker_id)
+        }
+
         /// Creates a new Sulid with the given datetime and random number generator
         ///
         /// This will take the maximum of the `[SystemTime]` argument and `[SystemTime::UNIX_EPOCH]`
@@ -637,7 +3625,7 @@ pub(crate) mod std_feature {
                 .unwrap_or(Duration::ZERO)
                 .as_millis();
             let timebits = (timestamp & bitmask!(Self::TIME_BITS => u128)) as u64;
-            let randbits = (source.gen::<u128>() & bitmask!(Self::RAND_BITS => u128)) as u128;
+            let randbits = source.gen::<u128>() & bitmask!(Self::RAND_BITS => u128);
             Sulid::v1_from_parts(timebits, randbits, data_center_id, machine_id)
         }
 
@@ -668,10 +3656,38 @@ pub(crate) mod std_feature {
                 .unwrap_or(Duration::ZERO)
                 .as_millis();
             let timebits = (timestamp & bitmask!(Self::TIME_BITS => u128)) as u64;
-            let randbits = (source.gen::<u128>() & bitmask!(Self::RAND_BITS => u128)) as u128;
+            let randbits = source.gen::<u128>() & bitmask!(Self::RAND_BITS => u128);
             Sulid::v2_from_parts(timebits, randbits, worker_id)
         }
 
+        /// Creates a Sulid with only the timestamp of the given datetime set
+        ///
+        /// The random section and worker bits are zero, so the result is the smallest Sulid
+        /// of its millisecond: a lower-bound key for time-based comparisons and range
+        /// queries. Unlike [`Sulid::v2_from_datetime`], no randomness is involved.
+        ///
+        /// Times before [`SystemTime::UNIX_EPOCH`] are clamped to the epoch, and timestamps
+        /// that don't fit into 48 bits are silently truncated.
+        ///
+        /// # Example
+        /// ```rust
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::Sulid;
+        ///
+        /// let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        /// let key = Sulid::from_system_time(time);
+        /// assert_eq!(key, Sulid::v2_from_parts(1_700_000_000_000, 0, 0));
+        /// assert_eq!(key, Sulid::from(time));
+        /// ```
+        pub fn from_system_time(datetime: SystemTime) -> Sulid {
+            let timestamp = datetime
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO)
+                .as_millis();
+            let timebits = (timestamp & bitmask!(Self::TIME_BITS => u128)) as u64;
+            Sulid::v2_from_parts(timebits, 0, 0)
+        }
+
         /// Gets the datetime of when this Sulid was created accurate to 1ms
         ///
         /// # Example
@@ -691,24 +3707,176 @@ pub(crate) mod std_feature {
             let stamp = self.timestamp_ms();
             SystemTime::UNIX_EPOCH + Duration::from_millis(stamp)
         }
-        /// Creates a Crockford Base32 encoded string that represents this Sulid
+
+        /// Gets the datetime of when this Sulid was created, reading its timestamp in `unit`
+        ///
+        /// This is the counterpart of a generator configured with
+        /// [`SulidGeneratorBuilder::time_unit`](crate::SulidGeneratorBuilder::time_unit),
+        /// accurate to one `unit`. [`Sulid::datetime`] is the same as reading in
+        /// [`TimeUnit::Millis`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use std::time::{Duration, SystemTime};
+        /// use sulid::{Sulid, TimeUnit};
+        ///
+        /// let sulid = Sulid::v2_from_parts(1_700_000_000, 42, 7);
+        /// assert_eq!(
+        ///     sulid.datetime_with_unit(TimeUnit::Seconds),
+        ///     SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        /// );
+        /// ```
+        pub fn datetime_with_unit(&self, unit: TimeUnit) -> SystemTime {
+            let stamp = self.timestamp_ms() * unit.as_millis();
+            SystemTime::UNIX_EPOCH + Duration::from_millis(stamp)
+        }
+
+        /// Checks whether the timestamp lies within `[now - max_age, now + max_skew]`
+        ///
+        /// This rejects both stale and implausibly future-dated Sulids in one check, e.g. for
+        /// anti-replay protection of tokens. Both bounds are inclusive and compared in whole
+        /// milliseconds.
+        ///
+        /// Timestamps can't be earlier than the Unix epoch: [`Sulid::v1_from_datetime`] and
+        /// friends clamp earlier datetimes to it. If `max_age` reaches back before the
+        /// epoch, the window simply starts at the epoch, so a Sulid with a timestamp of zero
+        /// is only fresh if `max_age` is at least the time elapsed since the epoch.
         ///
         /// # Example
         /// ```rust
+        /// use std::time::Duration;
         /// use sulid::Sulid;
         ///
-        /// let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
-        /// let sulid = Sulid::from_string(text).unwrap();
+        /// let sulid = Sulid::v2_new(1);
+        /// assert!(sulid.is_fresh(Duration::from_secs(60), Duration::from_secs(5)));
+        /// assert!(!Sulid::nil().is_fresh(Duration::from_secs(60), Duration::from_secs(5)));
+        /// ```
+        pub fn is_fresh(&self, max_age: Duration, max_skew: Duration) -> bool {
+            let now_ms = now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO)
+                .as_millis();
+            let timestamp_ms = u128::from(self.timestamp_ms());
+            now_ms.saturating_sub(max_age.as_millis()) <= timestamp_ms
+                && timestamp_ms <= now_ms.saturating_add(max_skew.as_millis())
+        }
+
+        /// Creates a deterministic Sulid at midnight UTC of the given date, for fixtures
         ///
-        /// assert_eq!(&sulid.to_string(), text);
+        /// `seq` becomes the random section, so Sulids of the same date sort by `seq`.
+        /// An [`IdRangeError::Date`] is returned for invalid dates (e.g. February 30) and
+        /// dates before 1970-01-01, and the errors of [`Sulid::v2_from_parts_checked`] for
+        /// dates, `seq` values and worker IDs that don't fit into their fields.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::for_date(2024, 2, 29, 1, 7).unwrap();
+        /// assert_eq!(sulid.timestamp_ms(), 1_709_164_800_000);
+        /// assert!(sulid < Sulid::for_date(2024, 2, 29, 2, 7).unwrap());
         /// ```
-        #[allow(clippy::inherent_to_string_shadow_display)] // Significantly faster than Display::to_string
-        pub fn to_string(&self) -> String {
-            self.0.to_string()
+        pub fn for_date(
+            year: i32,
+            month: u32,
+            day: u32,
+            seq: u128,
+            worker_id: u16,
+        ) -> Result<Sulid, IdRangeError> {
+            const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+            let days = days_from_civil(year, month, day).ok_or(IdRangeError::Date)?;
+            let timestamp_ms = days.checked_mul(DAY_MS).ok_or(IdRangeError::Timestamp)?;
+            Sulid::v2_from_parts_checked(timestamp_ms, seq, worker_id)
+        }
+
+        /// Returns a copy of this Sulid with a freshly drawn random section
+        ///
+        /// The timestamp and worker bits are kept, so the result is a sibling ID from the
+        /// same millisecond and worker, e.g. for stress-testing collision handling.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::example();
+        /// let sibling = sulid.reroll_random(&mut rand::thread_rng());
+        /// assert_eq!(sibling.timestamp_ms(), sulid.timestamp_ms());
+        /// assert_eq!(sibling.v2_worker_id(), sulid.v2_worker_id());
+        /// ```
+        pub fn reroll_random<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Sulid {
+            let random = rng.gen::<u128>() & bitmask!(Self::RAND_BITS => u128);
+            Sulid::v2_from_parts(self.timestamp_ms(), random, self.v2_worker_id())
+        }
+    }
+
+    impl From<SystemTime> for Sulid {
+        fn from(datetime: SystemTime) -> Self {
+            Sulid::from_system_time(datetime)
+        }
+    }
+
+    /// Computes how many years of timestamp headroom remain when timestamps count from `epoch`
+    ///
+    /// The 48-bit timestamp covers 2^48 ms, about 8919.6 years. The result is that span
+    /// minus the time elapsed from `epoch` until now, in Gregorian years of 365.2425 days.
+    /// Counting from the Unix epoch, the timestamp overflows in the year 10889; a later
+    /// epoch moves that point further out by the difference, which helps operators
+    /// weigh a custom epoch. An `epoch` in the future yields more than the full span.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::SystemTime;
+    ///
+    /// let headroom = sulid::timestamp_headroom_years(SystemTime::UNIX_EPOCH);
+    /// assert!(headroom > 8000.0);
+    /// ```
+    pub fn timestamp_headroom_years(epoch: SystemTime) -> f64 {
+        const MS_PER_YEAR: f64 = 365.2425 * 24.0 * 60.0 * 60.0 * 1000.0;
+        let elapsed_ms = match now().duration_since(epoch) {
+            Ok(elapsed) => elapsed.as_secs_f64() * 1000.0,
+            Err(err) => -err.duration().as_secs_f64() * 1000.0,
+        };
+        ((1u64 << Sulid::TIME_BITS) as f64 - elapsed_ms) / MS_PER_YEAR
+    }
+
+    /// Converts a proleptic Gregorian date to days since the Unix epoch.
+    ///
+    /// Returns `None` for invalid dates and dates before 1970-01-01. See Howard Hinnant's
+    /// `days_from_civil` algorithm.
+    fn days_from_civil(year: i32, month: u32, day: u32) -> Option<u64> {
+        let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if year < 1970 || day == 0 || day > days_in_month {
+            return None;
+        }
+        let year = year as u64 - u64::from(month <= 2);
+        let era = year / 400;
+        let yoe = year % 400;
+        let doy = (153 * u64::from((month + 9) % 12) + 2) / 5 + u64::from(day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        Some(era * 146_097 + doe - 719_468)
+    }
+
+    /// Converts a datetime to milliseconds since the Unix epoch, failing if it exceeds 48 bits.
+    fn checked_timestamp_ms(datetime: SystemTime) -> Result<u64, IdRangeError> {
+        let timestamp = datetime
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_millis();
+        if timestamp > bitmask!(Sulid::TIME_BITS => u128) {
+            Err(IdRangeError::Timestamp)
+        } else {
+            Ok(timestamp as u64)
         }
     }
 
-    fn now() -> std::time::SystemTime {
+    pub(crate) fn now() -> std::time::SystemTime {
         #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
         {
             use web_time::web::SystemTimeExt;
@@ -792,6 +3960,345 @@ pub(crate) mod std_feature {
             assert_eq!(Sulid::default(), Sulid::nil());
         }
 
+        #[test]
+        fn test_parse_canonical() {
+            use std::borrow::Cow;
+
+            let text = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+            let (sulid, s) = Sulid::parse_canonical(text).unwrap();
+            assert!(matches!(s, Cow::Borrowed(b) if std::ptr::eq(b, text)));
+            assert_eq!(sulid, Sulid::from_string(text).unwrap());
+
+            let lowercase = text.to_lowercase();
+            let (lower, s) = Sulid::parse_canonical(&lowercase).unwrap();
+            assert!(matches!(&s, Cow::Owned(o) if o == text));
+            assert_eq!(lower, sulid);
+
+            assert_eq!(
+                Sulid::parse_canonical("01D39ZY06FGSCTVN4T2V9PKHF"),
+                Err(DecodeError::InvalidLength)
+            );
+        }
+
+        #[test]
+        fn test_reroll_random() {
+            let sulid = Sulid::v1_from_parts(1_700_000_000_000, 42, 3, 4);
+            let mut rng = rand::thread_rng();
+            for _ in 0..100 {
+                let sibling = sulid.reroll_random(&mut rng);
+                assert_eq!(sibling.timestamp_ms(), sulid.timestamp_ms());
+                assert_eq!(sibling.v1_data_center_id(), 3);
+                assert_eq!(sibling.v1_machine_id(), 4);
+                assert_ne!(sibling.random(), sulid.random());
+            }
+        }
+
+        #[test]
+        fn test_decimal_round_trip() {
+            assert_eq!(Sulid::nil().to_decimal(), "0");
+            assert_eq!(
+                Sulid::MAX.to_decimal(),
+                "340282366920938463463374607431768211455"
+            );
+            for sulid in [Sulid::nil(), Sulid::MAX, Sulid::example()] {
+                assert_eq!(Sulid::from_decimal(&sulid.to_decimal()), Ok(sulid));
+            }
+        }
+
+        #[test]
+        fn test_to_string_lowercase() {
+            let ids = [
+                Sulid::nil(),
+                Sulid::v1_from_parts(1_700_000_000_000, 0x2A_DEAD_BEEF, 17, 29),
+                Sulid::v2_from_parts(1_700_000_000_001, 1, 1023),
+                Sulid::MAX,
+            ];
+            for sulid in ids {
+                let lower = sulid.to_string_lowercase();
+                assert_eq!(lower, sulid.to_string().to_lowercase());
+                assert_eq!(Sulid::from_string(&lower), Ok(sulid));
+                assert_eq!(Sulid::from_string(&sulid.to_string()), Ok(sulid));
+            }
+
+            let lower: Vec<_> = ids.iter().map(Sulid::to_string_lowercase).collect();
+            let mut sorted = lower.clone();
+            sorted.sort();
+            assert_eq!(lower, sorted);
+        }
+
+        #[test]
+        fn test_floor_ceil_now() {
+            const HOUR_MS: u64 = 60 * 60 * 1000;
+            let floor = Sulid::floor_now(HOUR_MS, 42);
+            let sulid = Sulid::v2_new(42);
+            let ceil = Sulid::ceil_now(HOUR_MS, 42);
+
+            // The generated ID could fall into the next hour if the clock just ticked over.
+            if sulid.time_bucket(HOUR_MS) == floor.time_bucket(HOUR_MS) {
+                assert!(floor <= sulid && sulid <= ceil);
+            }
+            assert_eq!(floor.timestamp_ms() % HOUR_MS, 0);
+            assert_eq!(floor.random(), 0);
+            assert_eq!(floor.v2_worker_id(), 42);
+            assert_eq!(ceil.timestamp_ms() % HOUR_MS, HOUR_MS - 1);
+            assert_eq!(ceil.random(), bitmask!(Sulid::RAND_BITS => u128));
+            assert_eq!(ceil.v2_worker_id(), 42);
+
+            let floor = Sulid::floor_now(0, 1);
+            let sulid = Sulid::v2_new(1);
+            let ceil = Sulid::ceil_now(0, 1);
+            assert!(floor.timestamp_ms() <= sulid.timestamp_ms());
+            assert!(sulid.timestamp_ms() <= ceil.timestamp_ms());
+        }
+
+        #[test]
+        fn test_proto_bytes() {
+            let sulid = Sulid::from_u128(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+            let bytes = sulid.to_proto_bytes();
+            assert_eq!(
+                bytes,
+                [
+                    0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76,
+                    0x54, 0x32, 0x10
+                ]
+            );
+            assert_eq!(Sulid::from_proto_bytes(&bytes), Ok(sulid));
+
+            let earlier = Sulid::v2_from_parts(1, u128::from(u64::MAX), 1023);
+            let later = Sulid::v2_from_parts(2, 0, 0);
+            assert!(earlier.to_proto_bytes() < later.to_proto_bytes());
+
+            assert_eq!(
+                Sulid::from_proto_bytes(&[]),
+                Err(DecodeError::InvalidLength)
+            );
+            assert_eq!(
+                Sulid::from_proto_bytes(&bytes[..15]),
+                Err(DecodeError::InvalidLength)
+            );
+            assert_eq!(
+                Sulid::from_proto_bytes(&[0; 17]),
+                Err(DecodeError::InvalidLength)
+            );
+        }
+
+        #[test]
+        fn test_for_date() {
+            assert_eq!(Sulid::for_date(1970, 1, 1, 0, 0), Ok(Sulid::nil()));
+            let sulid = Sulid::for_date(2023, 11, 14, 42, 3).unwrap();
+            assert_eq!(sulid.timestamp_ms(), 1_699_920_000_000);
+            assert_eq!(sulid.random(), 42);
+            assert_eq!(sulid.v2_worker_id(), 3);
+            assert_eq!(
+                Sulid::for_date(2000, 3, 1, 0, 0).unwrap().timestamp_ms(),
+                951_868_800_000
+            );
+            assert_eq!(
+                Sulid::for_date(10889, 8, 2, 0, 0).unwrap().timestamp_ms(),
+                281_474_956_800_000
+            );
+
+            assert_eq!(Sulid::for_date(2023, 2, 29, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(Sulid::for_date(1900, 2, 29, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(Sulid::for_date(2023, 13, 1, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(Sulid::for_date(2023, 4, 31, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(Sulid::for_date(2023, 4, 0, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(Sulid::for_date(1969, 12, 31, 0, 0), Err(IdRangeError::Date));
+            assert_eq!(
+                Sulid::for_date(10889, 8, 3, 0, 0),
+                Err(IdRangeError::Timestamp)
+            );
+            assert_eq!(
+                Sulid::for_date(i32::MAX, 1, 1, 0, 0),
+                Err(IdRangeError::Timestamp)
+            );
+            assert_eq!(
+                Sulid::for_date(2023, 1, 1, 0, 1024),
+                Err(IdRangeError::WorkerId)
+            );
+            assert_eq!(
+                Sulid::for_date(2023, 1, 1, 1 << Sulid::RAND_BITS, 0),
+                Err(IdRangeError::Random)
+            );
+        }
+
+        #[test]
+        fn test_base64url() {
+            assert_eq!(Sulid::nil().to_base64url(), "AAAAAAAAAAAAAAAAAAAAAA");
+            assert_eq!(Sulid::MAX.to_base64url(), "_____________________w");
+            assert_eq!(
+                Sulid::from_u128(0xFB).to_base64url(),
+                "AAAAAAAAAAAAAAAAAAAA-w"
+            );
+            for sulid in [
+                Sulid::nil(),
+                Sulid::MAX,
+                Sulid::example(),
+                Sulid::v1_from_parts(1_700_000_000_000, 0x2A_DEAD_BEEF, 17, 29),
+            ] {
+                let encoded = sulid.to_base64url();
+                assert_eq!(encoded.len(), 22);
+                assert_eq!(Sulid::from_base64url(&encoded), Ok(sulid));
+            }
+
+            assert_eq!(
+                Sulid::from_base64url("AWjT_wDPhlmt1JoW02nF_"),
+                Err(DecodeError::InvalidLength)
+            );
+            assert_eq!(
+                Sulid::from_base64url("AWjT_wDPhlmt1JoW02nF_w=="),
+                Err(DecodeError::InvalidLength)
+            );
+            assert_eq!(
+                Sulid::from_base64url("AWjT/wDPhlmt1JoW02nF_w"),
+                Err(DecodeError::InvalidChar)
+            );
+            assert_eq!(
+                Sulid::from_base64url("AWjT_wDPhlmt1JoW02nF_x"),
+                Err(DecodeError::InvalidChar)
+            );
+        }
+
+        #[test]
+        fn test_short_display() {
+            let ids = [
+                Sulid::from_string("01HF4ZQ8R0000000000000A001").unwrap(),
+                Sulid::from_string("01HF4ZQ8R0000000000000B001").unwrap(),
+                Sulid::from_string("01HF4ZQ8R00000000000000002").unwrap(),
+                Sulid::from_string("01HF4ZQ8R000000000000000C3").unwrap(),
+            ];
+            assert_eq!(ids[0].short_display(&ids), "A001");
+            assert_eq!(ids[1].short_display(&ids), "B001");
+            assert_eq!(ids[2].short_display(&ids), "2");
+            assert_eq!(ids[3].short_display(&ids), "3");
+
+            for sulid in ids {
+                let short = sulid.short_display(&ids);
+                let matches = ids
+                    .iter()
+                    .filter(|other| other.to_string().ends_with(&short))
+                    .count();
+                assert_eq!(matches, 1, "{}", short);
+            }
+
+            assert_eq!(ids[0].short_display(&[ids[0], ids[0]]), "1");
+            let neighbour = Sulid::from_u128(ids[0].u128() ^ (1 << 127));
+            assert_eq!(ids[0].short_display(&[neighbour]), ids[0].to_string());
+        }
+
+        #[test]
+        fn test_is_fresh() {
+            const MINUTE: Duration = Duration::from_secs(60);
+            let now = SystemTime::now();
+
+            let fresh = Sulid::v2_from_datetime(now, 1);
+            assert!(fresh.is_fresh(MINUTE, MINUTE));
+            assert!(fresh.is_fresh(MINUTE, Duration::ZERO));
+
+            let stale = Sulid::v2_from_datetime(now - 2 * MINUTE, 1);
+            assert!(!stale.is_fresh(MINUTE, MINUTE));
+            assert!(stale.is_fresh(3 * MINUTE, MINUTE));
+
+            let future = Sulid::v2_from_datetime(now + 2 * MINUTE, 1);
+            assert!(!future.is_fresh(MINUTE, MINUTE));
+            assert!(future.is_fresh(MINUTE, 3 * MINUTE));
+
+            // Datetimes before the epoch are clamped to a zero timestamp.
+            let clamped = Sulid::v2_from_datetime(SystemTime::UNIX_EPOCH - MINUTE, 1);
+            assert_eq!(clamped.timestamp_ms(), 0);
+            assert!(!clamped.is_fresh(MINUTE, MINUTE));
+            assert!(clamped.is_fresh(Duration::MAX, Duration::ZERO));
+            assert!(fresh.is_fresh(Duration::MAX, Duration::MAX));
+        }
+
+        #[test]
+        fn test_time_prefix() {
+            let dt = SystemTime::now();
+            let sulid1 = Sulid::v1_from_datetime(dt, 1, 1);
+            let sulid2 = Sulid::v2_from_datetime(dt, 1023);
+            assert_ne!(sulid1, sulid2);
+            assert_eq!(sulid1.time_prefix(), sulid2.time_prefix());
+            assert_eq!(sulid1.time_prefix().len(), 10);
+
+            let first = Sulid::v2_from_parts(sulid1.timestamp_ms(), 0, 0);
+            let last = Sulid::v2_from_parts(
+                sulid1.timestamp_ms(),
+                bitmask!(Sulid::RAND_BITS => u128),
+                1023,
+            );
+            assert_eq!(first.time_prefix(), sulid1.time_prefix());
+            assert_eq!(last.time_prefix(), sulid1.time_prefix());
+            let next_ms = Sulid::v2_from_parts(sulid1.timestamp_ms() + 1, 0, 0);
+            assert!(next_ms.time_prefix() > sulid1.time_prefix());
+        }
+
+        #[test]
+        fn test_timestamp_headroom_years() {
+            let unix = timestamp_headroom_years(SystemTime::UNIX_EPOCH);
+            let elapsed_years = now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs_f64()
+                / (365.2425 * 24.0 * 60.0 * 60.0);
+            assert_eq!((1970.0 + elapsed_years + unix) as u32, 10889);
+
+            let epoch_2020 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_577_836_800);
+            let headroom_2020 = timestamp_headroom_years(epoch_2020);
+            assert!((headroom_2020 - unix - 50.0).abs() < 0.01);
+
+            let future = now() + Duration::from_secs(365 * 24 * 60 * 60);
+            assert!(timestamp_headroom_years(future) > 8919.0);
+        }
+
+        #[test]
+        fn test_from_system_time() {
+            let time = SystemTime::UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+            let sulid = Sulid::from_system_time(time);
+            assert_eq!(sulid.timestamp_ms(), 1_700_000_000_123);
+            assert_eq!(sulid.random(), 0);
+            assert_eq!(sulid.v2_worker_id(), 0);
+            assert_eq!(Sulid::from(time), sulid);
+            assert!(sulid <= Sulid::v2_from_datetime(time, 0));
+
+            let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+            assert_eq!(Sulid::from_system_time(before_epoch), Sulid::nil());
+        }
+
+        #[test]
+        fn test_from_datetime_checked() {
+            let max = SystemTime::UNIX_EPOCH + Duration::from_millis((1 << 48) - 1);
+            let sulid = Sulid::v1_from_datetime_checked(max, 1, 2).unwrap();
+            assert_eq!(sulid.timestamp_ms(), (1 << 48) - 1);
+            assert_eq!(sulid.datetime(), max);
+            let sulid = Sulid::v2_from_datetime_checked(max, 1023).unwrap();
+            assert_eq!(sulid.timestamp_ms(), (1 << 48) - 1);
+
+            let overflow = max + Duration::from_millis(1);
+            assert_eq!(
+                Sulid::v1_from_datetime_checked(overflow, 1, 2),
+                Err(IdRangeError::Timestamp)
+            );
+            assert_eq!(
+                Sulid::v2_from_datetime_checked(overflow, 1023),
+                Err(IdRangeError::Timestamp)
+            );
+            // The unchecked constructor silently wraps around.
+            assert_eq!(Sulid::v2_from_datetime(overflow, 1023).timestamp_ms(), 0);
+
+            assert_eq!(
+                Sulid::v1_from_datetime_checked(max, 32, 2),
+                Err(IdRangeError::DataCenterId)
+            );
+        }
+
+        #[test]
+        fn generated_between_min_and_max() {
+            for _ in 0..16 {
+                let sulid = Sulid::v1_new(31, 31);
+                assert!(Sulid::MIN < sulid && sulid < Sulid::MAX);
+            }
+        }
+
         #[test]
         fn nil_is_at_unix_epoch() {
             assert_eq!(Sulid::nil().datetime(), SystemTime::UNIX_EPOCH);
@@ -812,3 +4319,82 @@ pub(crate) mod std_feature {
         }
     }
 }
+
+#[cfg(feature = "chrono")]
+pub(crate) mod chrono_feature {
+    use super::{bitmask, Sulid};
+    use chrono::{DateTime, Utc};
+
+    impl Sulid {
+        /// Gets the creation time of this Sulid as a chrono datetime, accurate to 1ms
+        ///
+        /// This requires the `chrono` feature.
+        ///
+        /// # Example
+        /// ```rust
+        /// use sulid::Sulid;
+        ///
+        /// let sulid = Sulid::v2_from_parts(1_700_000_000_123, 42, 7);
+        /// let datetime = sulid.to_datetime_utc();
+        /// assert_eq!(datetime.timestamp_millis(), 1_700_000_000_123);
+        /// ```
+        pub fn to_datetime_utc(&self) -> DateTime<Utc> {
+            // 48-bit timestamps are always within chrono's range.
+            DateTime::from_timestamp_millis(self.timestamp_ms() as i64)
+                .expect("48-bit timestamps are valid chrono datetimes")
+        }
+
+        /// Creates a new V2 Sulid with the given chrono datetime and worker ID
+        ///
+        /// Like [`Sulid::v2_from_datetime`], datetimes before the Unix epoch are clamped
+        /// to the epoch, timestamps that don't fit into 48 bits are silently truncated, and
+        /// the random section is drawn from the thread-local RNG.
+        ///
+        /// This requires the `chrono` feature.
+        ///
+        /// # Example
+        /// ```rust
+        /// use chrono::DateTime;
+        /// use sulid::Sulid;
+        ///
+        /// let datetime = DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+        /// let sulid = Sulid::from_chrono(datetime, 7);
+        /// assert_eq!(sulid.to_datetime_utc(), datetime);
+        /// assert_eq!(sulid.v2_worker_id(), 7);
+        /// ```
+        pub fn from_chrono(datetime: DateTime<Utc>, worker_id: u16) -> Sulid {
+            let timestamp = datetime.timestamp_millis().max(0) as u64;
+            let timebits = timestamp & bitmask!(Self::TIME_BITS => u64);
+            let randbits = rand::random::<u128>() & bitmask!(Self::RAND_BITS => u128);
+            Sulid::v2_from_parts(timebits, randbits, worker_id)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::TimeZone;
+
+        #[test]
+        fn chrono_round_trip() {
+            let datetime = Utc
+                .with_ymd_and_hms(2024, 2, 29, 12, 34, 56)
+                .unwrap()
+                .checked_add_signed(chrono::TimeDelta::milliseconds(789))
+                .unwrap();
+            let sulid = Sulid::from_chrono(datetime, 1023);
+            assert_eq!(sulid.to_datetime_utc(), datetime);
+            assert_eq!(sulid.v2_worker_id(), 1023);
+            assert_eq!(sulid.timestamp_ms(), 1_709_210_096_789);
+
+            let before_epoch = Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap();
+            assert_eq!(Sulid::from_chrono(before_epoch, 0).timestamp_ms(), 0);
+            assert_eq!(
+                Sulid::from_u128(u128::MAX)
+                    .to_datetime_utc()
+                    .timestamp_millis(),
+                bitmask!(Sulid::TIME_BITS => i64)
+            );
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Co-locating a tenant ID with the random section of a Sulid.
+
+use crate::sulid::bitmask;
+use crate::Sulid;
+
+impl Sulid {
+    /// The number of high bits of the random section holding the tenant ID, see
+    /// [`Sulid::from_parts_with_tenant`].
+    pub const TENANT_BITS: u8 = 16;
+
+    /// Creates a Sulid with a 16-bit tenant ID in the high bits of the random section
+    ///
+    /// The layout is otherwise the V2 layout: the timestamp still comes first, so Sulids
+    /// keep sorting by time, and IDs of the same millisecond sort by tenant. Routing
+    /// layers can read the tenant back with [`Sulid::tenant`] without a lookup.
+    ///
+    /// The tenant ID takes 16 of the 70 random bits, so only the low 54 bits of `random`
+    /// are kept. With 54 random bits, IDs from one worker and tenant within the same
+    /// millisecond reach a 50% collision probability after about 2^27 IDs instead of 2^35.
+    ///
+    /// NOTE: Any overflow bits in the given args are discarded
+    ///
+    /// # Example
+    /// ```rust
+    /// use sulid::Sulid;
+    ///
+    /// let sulid = Sulid::from_parts_with_tenant(1_700_000_000_000, 42, 0xABCDEF, 7);
+    /// assert_eq!(sulid.tenant(), 42);
+    /// assert_eq!(sulid.random_low_bits(54), 0xABCDEF);
+    /// assert_eq!(sulid.v2_worker_id(), 7);
+    /// ```
+    pub fn from_parts_with_tenant(
+        timestamp_ms: u64,
+        tenant: u16,
+        random: u128,
+        worker_id: u16,
+    ) -> Sulid {
+        const NONCE_BITS: u8 = Sulid::RAND_BITS - Sulid::TENANT_BITS;
+        let random = (tenant as u128) << NONCE_BITS | (random & bitmask!(NONCE_BITS => u128));
+        Sulid::v2_from_parts(timestamp_ms, random, worker_id)
+    }
+
+    /// Gets the tenant ID of a Sulid created by [`Sulid::from_parts_with_tenant`]
+    ///
+    /// For other Sulids this returns the top 16 random bits.
+    pub const fn tenant(&self) -> u16 {
+        self.random_high_bits(Self::TENANT_BITS) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_tenant() {
+        for tenant in [0, 1, 0x8000, u16::MAX] {
+            let sulid = Sulid::from_parts_with_tenant(1_700_000_000_000, tenant, u128::MAX, 1023);
+            assert_eq!(sulid.tenant(), tenant);
+            assert_eq!(sulid.random_low_bits(54), bitmask!(54 => u128));
+            assert_eq!(sulid.timestamp_ms(), 1_700_000_000_000);
+            assert_eq!(sulid.v2_worker_id(), 1023);
+        }
+    }
+
+    #[test]
+    fn preserves_sort_order() {
+        let ids = [
+            Sulid::from_parts_with_tenant(1, u16::MAX, u128::MAX, 1023),
+            Sulid::from_parts_with_tenant(2, 0, 0, 0),
+            Sulid::from_parts_with_tenant(2, 0, 5, 0),
+            Sulid::from_parts_with_tenant(2, 1, 0, 0),
+            Sulid::from_parts_with_tenant(3, 0, 0, 0),
+        ];
+        assert!(crate::is_sorted(&ids));
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
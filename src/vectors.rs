@@ -0,0 +1,113 @@
+//! Canonical test vectors for cross-implementation compatibility checks.
+
+/// A test vector: `(timestamp_ms, random, data_center_id, machine_id, u128, string)`.
+pub type TestVector = (u64, u128, u8, u8, u128, &'static str);
+
+const TEST_VECTORS: &[TestVector] = &[
+    (0, 0x0, 0, 0, 0x0, "00000000000000000000000000"),
+    (
+        281474976710655,
+        0x3f_ffff_ffff_ffff_ffff,
+        31,
+        31,
+        0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+    ),
+    (
+        1,
+        0x1,
+        1,
+        1,
+        0x1_0000_0000_0000_0000_0421,
+        "00000000010000000000000111",
+    ),
+    (0, 0x1, 0, 0, 0x400, "00000000000000000000000100"),
+    (
+        281474976710655,
+        0x0,
+        0,
+        0,
+        0xffff_ffff_ffff_0000_0000_0000_0000_0000,
+        "7ZZZZZZZZZ0000000000000000",
+    ),
+    (
+        1469918176385,
+        0x0,
+        31,
+        0,
+        0x156_3df3_6481_0000_0000_0000_0000_03e0,
+        "01ARYZ6S4100000000000000Z0",
+    ),
+    (
+        1700000000000,
+        0x0,
+        0,
+        0,
+        0x18b_cfe5_6800_0000_0000_0000_0000_0000,
+        "01HF7YAT000000000000000000",
+    ),
+    (
+        1700000000000,
+        0x3f_ffff_ffff_ffff_ffff,
+        0,
+        31,
+        0x18b_cfe5_6800_ffff_ffff_ffff_ffff_fc1f,
+        "01HF7YAT00ZZZZZZZZZZZZZZ0Z",
+    ),
+    (
+        1700000000000,
+        0x2a_1234_5678_9abc_def0,
+        17,
+        5,
+        0x18b_cfe5_6800_a848_d159_e26a_f37b_c225,
+        "01HF7YAT00N14D2PF2DBSQQGH5",
+    ),
+];
+
+/// Returns a fixed table of V1 test vectors.
+///
+/// Each entry lists the parts passed to [`Sulid::v1_from_parts`](crate::Sulid::v1_from_parts)
+/// together with the expected integer representation and the expected Crockford Base32
+/// string. Other SULID implementations can use the table to verify that they encode
+/// identically. The V2 equivalent of each entry uses the worker ID
+/// [`combine_worker_id(data_center_id, machine_id)`](crate::combine_worker_id).
+///
+/// # Example
+/// ```rust
+/// use sulid::Sulid;
+///
+/// for &(timestamp_ms, random, data_center_id, machine_id, value, text) in sulid::test_vectors() {
+///     let sulid = Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id);
+///     assert_eq!(sulid.u128(), value);
+///     assert_eq!(sulid, Sulid::from_string(text).unwrap());
+/// }
+/// ```
+pub fn test_vectors() -> &'static [TestVector] {
+    TEST_VECTORS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combine_worker_id, Sulid, ULID_LEN};
+
+    #[test]
+    fn vectors_encode_as_listed() {
+        for &(timestamp_ms, random, data_center_id, machine_id, value, text) in test_vectors() {
+            let sulid = Sulid::v1_from_parts(timestamp_ms, random, data_center_id, machine_id);
+            assert_eq!(sulid.u128(), value);
+
+            let mut buffer = [0; ULID_LEN];
+            assert_eq!(sulid.array_to_str(&mut buffer), text);
+            assert_eq!(Sulid::from_string(text), Ok(sulid));
+
+            assert_eq!(sulid.timestamp_ms(), timestamp_ms);
+            assert_eq!(sulid.random(), random);
+            assert_eq!(sulid.v1_data_center_id(), data_center_id);
+            assert_eq!(sulid.v1_machine_id(), machine_id);
+
+            let worker_id = combine_worker_id(data_center_id, machine_id);
+            assert_eq!(Sulid::v2_from_parts(timestamp_ms, random, worker_id), sulid);
+        }
+    }
+}